@@ -0,0 +1,216 @@
+use crate::string::closest_match;
+
+const FLAG_PREFIX: &str = "-";
+const LONG_FLAG_PREFIX: &str = "--";
+const END_OF_FLAGS: &str = "--";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Flag {
+    pub long: &'static str,
+    pub short: Option<&'static str>,
+    pub value: Option<&'static str>,
+    pub description: &'static str,
+}
+
+impl Flag {
+    #[inline]
+    fn matches(&self, name: &str) -> bool {
+        self.long == name || self.short == Some(name)
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Args {
+    pub positionals: Vec<String>,
+    flags: Vec<(&'static str, Option<String>)>,
+    unknown_flags: Vec<String>,
+}
+
+impl Args {
+    pub fn parse(raw_args: &[String], known_flags: &[Flag]) -> Result<Self, String> {
+        let mut args = Self::default();
+        let mut iter = raw_args.iter().map(|arg| arg.trim());
+
+        while let Some(arg) = iter.next() {
+            if arg == END_OF_FLAGS {
+                args.positionals.extend(iter.map(|arg| arg.to_string()));
+                break;
+            }
+
+            if !arg.starts_with(FLAG_PREFIX) || arg == FLAG_PREFIX {
+                args.positionals.push(arg.to_string());
+                continue;
+            }
+
+            let (name, inline_value) = match arg.split_once('=') {
+                Some((name, value)) if name.starts_with(LONG_FLAG_PREFIX) => (name, Some(value)),
+                _ => (arg, None),
+            };
+
+            let Some(flag) = known_flags.iter().find(|f| f.matches(name)) else {
+                args.unknown_flags.push(name.to_string());
+                continue;
+            };
+
+            let value = match (flag.value, inline_value) {
+                (Some(_), Some(value)) => Some(value.to_string()),
+                (Some(placeholder), None) => match iter.next() {
+                    Some(value) if !value.starts_with(LONG_FLAG_PREFIX) => Some(value.to_string()),
+                    _ => {
+                        return Err(format!(
+                            "The option `{}` requires a {} value",
+                            flag.long, placeholder
+                        ))
+                    }
+                },
+                (None, Some(_)) => {
+                    return Err(format!("The option `{}` does not take a value", flag.long))
+                }
+                (None, None) => None,
+            };
+
+            args.flags.retain(|(long, _)| *long != flag.long);
+            args.flags.push((flag.long, value));
+        }
+
+        Ok(args)
+    }
+
+    #[inline]
+    pub fn has(&self, long: &str) -> bool {
+        self.flags.iter().any(|(name, _)| *name == long)
+    }
+
+    #[inline]
+    pub fn value(&self, long: &str) -> Option<&str> {
+        self.flags
+            .iter()
+            .find(|(name, _)| *name == long)
+            .and_then(|(_, value)| value.as_deref())
+    }
+
+    pub fn ensure_flags(&self, allowed_flags: &[Flag], context: &str) -> Result<(), String> {
+        let unsupported = self
+            .unknown_flags
+            .iter()
+            .map(|name| name.as_str())
+            .chain(
+                self.flags
+                    .iter()
+                    .map(|(name, _)| *name)
+                    .filter(|name| !allowed_flags.iter().any(|f| f.long == *name)),
+            )
+            .next();
+
+        let Some(name) = unsupported else {
+            return Ok(());
+        };
+
+        let suggestion = closest_match(
+            name,
+            allowed_flags
+                .iter()
+                .flat_map(|f| std::iter::once(f.long).chain(f.short)),
+        );
+
+        Err(match suggestion {
+            Some(suggestion) => format!(
+                "Unknown option `{}` for `{}`, did you mean `{}`",
+                name, context, suggestion
+            ),
+            None => format!("Unknown option `{}` for `{}`", name, context),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NAME: Flag = Flag {
+        long: "--name",
+        short: Some("-n"),
+        value: Some("NAME"),
+        description: "",
+    };
+    const FORCE: Flag = Flag {
+        long: "--force",
+        short: Some("-f"),
+        value: None,
+        description: "",
+    };
+    const FLAGS: &[Flag] = &[NAME, FORCE];
+
+    fn parse(raw_args: &[&str]) -> Result<Args, String> {
+        let raw_args: Vec<String> = raw_args.iter().map(|arg| arg.to_string()).collect();
+        Args::parse(&raw_args, FLAGS)
+    }
+
+    #[test]
+    fn parses_long_short_and_inline_values() {
+        let args = parse(&["go", "--name", "api", "-f", "."]).unwrap();
+        assert_eq!(args.positionals, vec!["go", "."]);
+        assert_eq!(args.value("--name"), Some("api"));
+        assert!(args.has("--force"));
+
+        let args = parse(&["-n", "api"]).unwrap();
+        assert_eq!(args.value("--name"), Some("api"));
+
+        let args = parse(&["--name=api"]).unwrap();
+        assert_eq!(args.value("--name"), Some("api"));
+    }
+
+    #[test]
+    fn keeps_the_last_occurrence_of_a_flag() {
+        let args = parse(&["--name", "first", "-n", "second"]).unwrap();
+        assert_eq!(args.value("--name"), Some("second"));
+    }
+
+    #[test]
+    fn treats_arguments_after_end_of_flags_as_positionals() {
+        let args = parse(&["--", "--force", "-"]).unwrap();
+        assert_eq!(args.positionals, vec!["--force", "-"]);
+        assert!(!args.has("--force"));
+    }
+
+    #[test]
+    fn rejects_missing_and_unexpected_values() {
+        assert_eq!(
+            parse(&["--name"]).unwrap_err(),
+            "The option `--name` requires a NAME value"
+        );
+        assert_eq!(
+            parse(&["--name", "--force"]).unwrap_err(),
+            "The option `--name` requires a NAME value"
+        );
+        assert_eq!(
+            parse(&["--force=yes"]).unwrap_err(),
+            "The option `--force` does not take a value"
+        );
+    }
+
+    #[test]
+    fn suggests_the_closest_flag() {
+        let args = parse(&["--nmae", "api"]).unwrap();
+        assert_eq!(
+            args.ensure_flags(FLAGS, "go").unwrap_err(),
+            "Unknown option `--nmae` for `go`, did you mean `--name`"
+        );
+
+        let args = parse(&["--database"]).unwrap();
+        assert_eq!(
+            args.ensure_flags(FLAGS, "go").unwrap_err(),
+            "Unknown option `--database` for `go`"
+        );
+    }
+
+    #[test]
+    fn rejects_known_flags_not_allowed_in_context() {
+        let args = parse(&["--force"]).unwrap();
+        assert!(args.ensure_flags(FLAGS, "go").is_ok());
+        assert_eq!(
+            args.ensure_flags(&[NAME], "import").unwrap_err(),
+            "Unknown option `--force` for `import`"
+        );
+    }
+}
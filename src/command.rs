@@ -1,16 +1,345 @@
-use crate::lang::Lang;
+use std::path::PathBuf;
+
+use crate::arg::{Args, Flag};
+use crate::completion::{Shell, COMPLETE_ARG};
+use crate::lang::{Lang, NAMES as LANG_NAMES};
 use crate::os::OS;
+use crate::print::{disable_color, is_no_color_env, set_output, set_verbosity, Output, Verbosity};
 use crate::source::Source;
-use crate::string::split_text;
+use crate::string::{closest_match, split_text};
 use crate::structure::{
     Part as StructurePart, Partial as StructurePartial, Structure, Template as StructureTemplate,
     Version as StructureVersion,
 };
 use crate::sys::cwd;
 
+const UPDATER_TASK_ARG: &str = "--updater-task";
+const UPDATER_TASK_CLEARANCE_ARG: &str = "--updater-task-clearance";
+
+pub const HELP_FLAG: Flag = Flag {
+    long: "--help",
+    short: Some("-h"),
+    value: None,
+    description: "Show help for the command",
+};
+
+pub const OUTPUT_FLAG: Flag = Flag {
+    long: "--output",
+    short: None,
+    value: Some("FORMAT"),
+    description: "Set the output format: text or json",
+};
+
+pub const QUIET_FLAG: Flag = Flag {
+    long: "--quiet",
+    short: Some("-q"),
+    value: None,
+    description: "Hide the progress lines and the output of external commands unless a step fails",
+};
+
+pub const VERBOSE_FLAG: Flag = Flag {
+    long: "--verbose",
+    short: Some("-v"),
+    value: None,
+    description: "Show the command line, working directory and timing of external commands",
+};
+
+pub const NO_COLOR_FLAG: Flag = Flag {
+    long: "--no-color",
+    short: None,
+    value: None,
+    description: "Disable colored output, the NO_COLOR environment variable does the same",
+};
+
+pub const GLOBAL_FLAGS: &[Flag] = &[HELP_FLAG, OUTPUT_FLAG, QUIET_FLAG, VERBOSE_FLAG, NO_COLOR_FLAG];
+
+pub const DRY_RUN_FLAG: Flag = Flag {
+    long: "--dry-run",
+    short: None,
+    value: None,
+    description: "List the changes without touching the disk",
+};
+
+pub const DATABASE_FLAG: Flag = Flag {
+    long: "--db",
+    short: None,
+    value: Some("DATABASE"),
+    description: "Set the database used by the repositories (Go, Python, TS, NodeJS and Rust)",
+};
+
+pub const GENERATOR_FLAGS: &[Flag] = &[
+    Flag {
+        long: "--name",
+        short: None,
+        value: Some("NAME"),
+        description: "Set the project name",
+    },
+    Flag {
+        long: "--into",
+        short: None,
+        value: Some("DIR"),
+        description: "Generate into the given directory, it may already exist",
+    },
+    Flag {
+        long: "--module",
+        short: None,
+        value: Some("MODULE"),
+        description: "Set the Go module, Java base package or C#/PHP root namespace",
+    },
+    Flag {
+        long: "--no-vendor",
+        short: None,
+        value: None,
+        description: "Disable Go vendoring or Python virtual environment (venv), not with uv",
+    },
+    Flag {
+        long: "--python-cmd",
+        short: None,
+        value: Some("COMMAND"),
+        description: "Set the Python command used for post-installation setup",
+    },
+    Flag {
+        long: "--python-tool",
+        short: None,
+        value: Some("TOOLCHAIN"),
+        description: "Set the Python toolchain used to manage dependencies: pip, uv or poetry",
+    },
+    Flag {
+        long: "--http",
+        short: None,
+        value: Some("FRAMEWORK"),
+        description: "Set the HTTP framework used by the generated service",
+    },
+    DATABASE_FLAG,
+    Flag {
+        long: "--port",
+        short: None,
+        value: Some("PORT"),
+        description: "Set the port the generated service listens on",
+    },
+    Flag {
+        long: "--pm",
+        short: None,
+        value: Some("PACKAGE_MANAGER"),
+        description: "Set the package manager for TypeScript, NodeJS or SvelteKit projects",
+    },
+    Flag {
+        long: "--git",
+        short: None,
+        value: None,
+        description: "Initialize a Git repository and create the first commit",
+    },
+    Flag {
+        long: "--git-message",
+        short: None,
+        value: Some("MESSAGE"),
+        description: "Set the first commit message, it implies --git",
+    },
+    Flag {
+        long: "--git-author",
+        short: None,
+        value: Some("AUTHOR"),
+        description: "Set the first commit author as 'Name <email>', it implies --git",
+    },
+    Flag {
+        long: "--minimal",
+        short: None,
+        value: None,
+        description: "Skip the todo example feature and generate a main file with no routes",
+    },
+    Flag {
+        long: "--offline",
+        short: None,
+        value: None,
+        description: "Skip the toolchain commands and write them into infra/setup.sh and setup.ps1",
+    },
+    Flag {
+        long: "--answers",
+        short: None,
+        value: Some("FILE"),
+        description: "Read the answers from a TOML or JSON file",
+    },
+    Flag {
+        long: "--keep-staging",
+        short: None,
+        value: None,
+        description: "Keep the staging directory when the generation fails, for debugging",
+    },
+    Flag {
+        long: "--yes",
+        short: Some("-y"),
+        value: None,
+        description: "Skip the confirmation and take the default of every other prompt",
+    },
+    DRY_RUN_FLAG,
+];
+
+#[derive(Debug)]
+pub struct Arg {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub is_required: bool,
+}
+
+#[derive(Debug)]
+pub struct Spec {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub args: &'static [Arg],
+    pub flags: &'static [Flag],
+}
+
+impl Spec {
+    fn ensure_args(&self, context: &str, positionals: &[String]) -> Result<(), String> {
+        if let Some(missing) = self
+            .args
+            .iter()
+            .filter(|arg| arg.is_required)
+            .nth(positionals.len())
+        {
+            return Err(format!("Missing {} for `{}`", missing.name, context));
+        }
+
+        if let Some(unexpected) = positionals.get(self.args.len()) {
+            return Err(format!(
+                "Unexpected argument `{}` for `{}`",
+                unexpected, context
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn allowed_flags(&self) -> Vec<Flag> {
+        self.flags
+            .iter()
+            .chain(GLOBAL_FLAGS)
+            .copied()
+            .collect()
+    }
+}
+
+pub const HELP_SPEC: Spec = Spec {
+    name: "help",
+    description: "Show the help message",
+    args: &[Arg {
+        name: "COMMAND",
+        description: "Show the help message of the given command",
+        is_required: false,
+    }],
+    flags: &[],
+};
+
+pub const VERSION_SPEC: Spec = Spec {
+    name: "version",
+    description: "Get the current uwais version",
+    args: &[],
+    flags: &[],
+};
+
+pub const UPDATE_SPEC: Spec = Spec {
+    name: "update",
+    description: "Update to the latest uwais version",
+    args: &[],
+    flags: &[],
+};
+
+pub const GENERATOR_SPEC: Spec = Spec {
+    name: "LANGUAGE",
+    description: "Generate a new project",
+    args: &[
+        Arg {
+            name: "VERSION",
+            description: "The structure version",
+            is_required: false,
+        },
+        Arg {
+            name: "DIR",
+            description: "Generate into the given directory, `.` is the current directory",
+            is_required: false,
+        },
+    ],
+    flags: GENERATOR_FLAGS,
+};
+
+pub const IMPORT_SPEC: Spec = Spec {
+    name: "import",
+    description: "Import function(s) from a source project",
+    args: &[
+        Arg {
+            name: "PART",
+            description: "The part to import: common, domain or feature",
+            is_required: true,
+        },
+        Arg {
+            name: "NAMES",
+            description: "The names to import, separated by commas",
+            is_required: true,
+        },
+        Arg {
+            name: "SOURCE",
+            description: "A local path or a Git URL of the source project",
+            is_required: true,
+        },
+    ],
+    flags: &[DRY_RUN_FLAG],
+};
+
+pub const ADD_SPEC: Spec = Spec {
+    name: "add",
+    description: "Add template(s) to the current project",
+    args: &[
+        Arg {
+            name: "PART",
+            description: "The part to add: common, domain or feature",
+            is_required: true,
+        },
+        Arg {
+            name: "NAMES",
+            description: "The names to add, separated by commas",
+            is_required: true,
+        },
+    ],
+    flags: &[DATABASE_FLAG, DRY_RUN_FLAG],
+};
+
+pub const COMPLETIONS_SPEC: Spec = Spec {
+    name: "completions",
+    description: "Print the shell completion script",
+    args: &[Arg {
+        name: "SHELL",
+        description: "The shell: bash, zsh, fish or powershell",
+        is_required: true,
+    }],
+    flags: &[],
+};
+
+pub const LIST_SPEC: Spec = Spec {
+    name: "list",
+    description: "List the supported languages, structure versions and generated files",
+    args: &[],
+    flags: &[Flag {
+        long: "--json",
+        short: None,
+        value: None,
+        description: "Print the list as JSON",
+    }],
+};
+
+pub const SPECS: &[&Spec] = &[
+    &HELP_SPEC,
+    &VERSION_SPEC,
+    &UPDATE_SPEC,
+    &IMPORT_SPEC,
+    &ADD_SPEC,
+    &COMPLETIONS_SPEC,
+    &LIST_SPEC,
+];
+
 #[derive(Debug)]
 pub enum Command {
     Help,
+    HelpFor(String, &'static Spec),
     Version,
     Update(Source),
     UpdaterTask,
@@ -18,140 +347,445 @@ pub enum Command {
     Import(StructurePartial, Source, Vec<String>),
     Generator(Structure),
     Add(StructureTemplate, Vec<String>),
-    Unknown,
+    List(bool),
+    Completions(Shell),
+    Complete(String, usize),
+    Unknown(String),
 }
 
 impl Command {
-    pub fn new(os: OS, args: &[String]) -> Self {
-        if args.is_empty() {
-            return Self::Help;
+    pub fn new(os: OS, raw_args: &[String]) -> Self {
+        match raw_args {
+            [] => return Self::Help,
+            [arg] if arg == UPDATER_TASK_ARG => return Self::UpdaterTask,
+            [arg] if arg == UPDATER_TASK_CLEARANCE_ARG => return Self::UpdaterTaskClearance,
+            [arg, point, line @ ..] if arg == COMPLETE_ARG => {
+                return match point.parse() {
+                    Ok(point) => Self::Complete(line.join(" "), point),
+                    Err(_) => Self::Unknown(format!("Invalid completion cursor `{}`", point)),
+                }
+            }
+            _ => {}
         }
 
-        match args.len() {
-            1 => Self::parse_single_arg(&os, &args[0]),
-            2 => Self::parse_two_args(&os, &args[0], &args[1]),
-            3 => Self::parse_three_args(&os, args),
-            4 => Self::parse_four_args(&os, args),
-            _ => Self::Unknown,
+        if is_no_color_env() {
+            disable_color();
         }
-    }
 
-    fn parse_single_arg(os: &OS, arg: &str) -> Self {
-        let arg = arg.trim();
-        match arg {
-            "" | "help" => Self::Help,
-            "version" => Self::Version,
-            "update" => Self::Update(Source::new_latest_app_release(os.clone())),
-            "--updater-task" => Self::UpdaterTask,
-            "--updater-task-clearance" => Self::UpdaterTaskClearance,
-            _ => Self::try_parse_language_command(os, arg, None),
-        }
-    }
+        let args = match Args::parse(raw_args, &known_flags()) {
+            Ok(args) => args,
+            Err(err) => return Self::Unknown(err),
+        };
 
-    fn parse_two_args(os: &OS, arg1: &str, arg2: &str) -> Self {
-        let arg1 = arg1.trim();
-        let arg2 = arg2.trim();
+        if args.has(NO_COLOR_FLAG.long) {
+            disable_color();
+        }
 
-        if arg1.is_empty() || arg2.is_empty() {
-            return Self::Unknown;
+        match (args.has(QUIET_FLAG.long), args.has(VERBOSE_FLAG.long)) {
+            (true, true) => {
+                return Self::Unknown(format!(
+                    "`{}` and `{}` cannot be used together",
+                    QUIET_FLAG.long, VERBOSE_FLAG.long
+                ))
+            }
+            (true, false) => set_verbosity(Verbosity::Quiet),
+            (false, true) => set_verbosity(Verbosity::Verbose),
+            (false, false) => {}
         }
 
-        Self::try_parse_language_command(os, arg1, Some(arg2))
-    }
+        if let Some(format) = args.value(OUTPUT_FLAG.long) {
+            match Output::from_name(format) {
+                Some(output) => set_output(output),
+                None => return Self::Unknown(unknown_output_message(format)),
+            }
+        }
 
-    fn parse_three_args(os: &OS, args: &[String]) -> Self {
-        let command = args[0].trim();
-        let subcommand = args[1].trim();
-        let names = args[2].trim();
+        let Some((name, positionals)) = args.positionals.split_first() else {
+            return match args.ensure_flags(GLOBAL_FLAGS, "uwais") {
+                Ok(_) => Self::Help,
+                Err(err) => Self::Unknown(err),
+            };
+        };
 
-        if command.is_empty() || subcommand.is_empty() || names.is_empty() {
-            return Self::Unknown;
+        if name.is_empty() {
+            return Self::Help;
         }
 
-        match command {
-            "add" => Self::parse_add_command(os, subcommand, names),
-            _ => Self::Unknown,
+        let Some(spec) = find_spec(name) else {
+            return Self::Unknown(unknown_command_message(name));
+        };
+
+        if let Err(err) = args.ensure_flags(&spec.allowed_flags(), name) {
+            return Self::Unknown(err);
         }
-    }
 
-    fn parse_four_args(os: &OS, args: &[String]) -> Self {
-        let command = args[0].trim();
-        let subcommand = args[1].trim();
-        let names = args[2].trim();
-        let source = args[3].trim();
+        if args.has(HELP_FLAG.long) {
+            return Self::HelpFor(name.to_string(), spec);
+        }
 
-        if command.is_empty() || subcommand.is_empty() || names.is_empty() || source.is_empty() {
-            return Self::Unknown;
+        if let Err(err) = spec.ensure_args(name, positionals) {
+            return Self::Unknown(err);
         }
 
-        match command {
-            "import" => Self::parse_import_command(os, subcommand, names, source),
-            _ => Self::Unknown,
+        match spec.name {
+            "help" => Self::parse_help_command(positionals.first()),
+            "version" => Self::Version,
+            "update" => Self::Update(Source::new_latest_app_release(os.clone())),
+            "import" => Self::parse_import_command(
+                &os,
+                &positionals[0],
+                &positionals[1],
+                &positionals[2],
+                args.has(DRY_RUN_FLAG.long),
+            ),
+            "list" => Self::List(args.has("--json") || args.value(OUTPUT_FLAG.long) == Some("json")),
+            "completions" => Self::parse_completions_command(&positionals[0]),
+            "add" => Self::parse_add_command(
+                &os,
+                &positionals[0],
+                &positionals[1],
+                args.value(DATABASE_FLAG.long),
+                args.has(DRY_RUN_FLAG.long),
+            ),
+            _ => Self::parse_generator_command(&os, name, positionals, &args),
         }
     }
 
-    fn try_parse_language_command(os: &OS, lang_name: &str, version: Option<&str>) -> Self {
-        if lang_name.trim().is_empty() {
-            return Self::Unknown;
+    fn parse_help_command(name: Option<&String>) -> Self {
+        let Some(name) = name.filter(|n| !n.is_empty()) else {
+            return Self::Help;
+        };
+
+        match find_spec(name) {
+            Some(spec) => Self::HelpFor(name.to_string(), spec),
+            None => Self::Unknown(unknown_command_message(name)),
         }
+    }
 
-        let lang = Lang::new(os.clone(), lang_name);
+    fn parse_generator_command(
+        os: &OS,
+        lang_name: &str,
+        positionals: &[String],
+        args: &Args,
+    ) -> Self {
+        let mut lang = Lang::new(os.clone(), lang_name);
         if !lang.is_valid() {
-            return Self::Unknown;
+            return Self::Unknown(unknown_command_message(lang_name));
+        }
+
+        let (version, dir) = match positionals {
+            [arg] if !arg.is_empty() && StructureVersion::from_name(arg).is_none() => {
+                (None, Some(arg))
+            }
+            [version, dir] => (Some(version), Some(dir)),
+            [version] => (Some(version), None),
+            _ => (None, None),
+        };
+
+        let structure_version = match version.filter(|v| !v.is_empty()) {
+            Some(version) => match StructureVersion::from_name(version) {
+                Some(structure_version) => structure_version,
+                None => return Self::Unknown(unknown_version_message(version)),
+            },
+            None => StructureVersion::default(),
+        };
+
+        lang.preset.project_name = args.value("--name").map(String::from);
+        lang.preset.module_name = args.value("--module").map(String::from);
+        lang.preset.python_command = args.value("--python-cmd").map(String::from);
+        lang.preset.python_toolchain = args.value("--python-tool").map(String::from);
+        lang.preset.http_framework = args.value("--http").map(String::from);
+        lang.preset.database = args.value("--db").map(String::from);
+        lang.preset.node_package_manager = args.value("--pm").map(String::from);
+        lang.preset.port = args.value("--port").map(String::from);
+        lang.preset.is_git_init = args.has("--git").then_some(true);
+        lang.preset.git_message = args.value("--git-message").map(String::from);
+        lang.preset.git_author = args.value("--git-author").map(String::from);
+        lang.preset.answers_file = args.value("--answers").map(PathBuf::from);
+        lang.preset.is_assume_yes = args.has("--yes");
+        lang.preset.project_dir = match (args.value("--into"), dir) {
+            (Some(_), Some(dir)) => {
+                return Self::Unknown(format!(
+                    "Unexpected argument `{}`, the directory is already given by `--into`",
+                    dir
+                ))
+            }
+            (Some(into), None) => Some(PathBuf::from(into)),
+            (None, dir) => dir.map(PathBuf::from),
+        };
+        if args.has("--no-vendor") {
+            lang.preset.is_use_vendoring = Some(false);
         }
 
-        let structure_version = version
-            .filter(|v| !v.trim().is_empty())
-            .map(StructureVersion::from)
-            .unwrap_or_default();
+        lang.set_offline(args.has("--offline"));
 
-        Self::Generator(Structure::new(structure_version, lang))
+        let mut structure = Structure::new(structure_version, lang);
+
+        structure.is_confirmed = args.has("--yes");
+        structure.is_dry_run = args.has(DRY_RUN_FLAG.long);
+        structure.is_keep_staging = args.has("--keep-staging");
+        structure.is_minimal = args.has("--minimal");
+        structure.is_offline = args.has("--offline");
+
+        Self::Generator(structure)
     }
 
-    fn parse_add_command(os: &OS, part: &str, names: &str) -> Self {
-        if part.is_empty() || names.is_empty() {
-            return Self::Unknown;
+    fn parse_completions_command(shell: &str) -> Self {
+        match Shell::from_name(shell) {
+            Some(shell) => Self::Completions(shell),
+            None => Self::Unknown(format!(
+                "Unknown shell `{}`, available shells: {}",
+                shell,
+                Shell::NAMES.join(", ")
+            )),
         }
+    }
 
-        let current_dir = cwd();
-        let lang = Lang::new_from_dir(os.clone(), &current_dir);
-        let template =
-            StructureTemplate::new(StructureVersion::default(), StructurePart::from(part), lang);
-
-        let name_list: Vec<String> = parse_comma_separated(names)
-            .into_iter()
-            .map(|n| n.trim().to_string())
-            .filter(|n| !n.is_empty())
-            .collect();
+    fn parse_add_command(
+        os: &OS,
+        part: &str,
+        names: &str,
+        database: Option<&str>,
+        is_dry_run: bool,
+    ) -> Self {
+        let part = match parse_part(part, "add") {
+            Ok(part) => part,
+            Err(err) => return Self::Unknown(err),
+        };
+
+        let name_list = parse_names(names);
         if name_list.is_empty() {
-            return Self::Unknown;
+            return Self::Unknown("No names were given for `add`".to_string());
         }
 
+        let current_dir = cwd();
+        let mut lang = Lang::new_from_dir(os.clone(), &current_dir);
+        lang.preset.database = database.map(String::from);
+        let mut template = StructureTemplate::new(StructureVersion::default(), part, lang);
+
+        template.is_dry_run = is_dry_run;
+
         Self::Add(template, name_list)
     }
 
-    fn parse_import_command(os: &OS, part: &str, names: &str, source_url: &str) -> Self {
-        if part.is_empty() || names.is_empty() || source_url.is_empty() {
-            return Self::Unknown;
-        }
-
-        let partial = StructurePartial::new(StructureVersion::default(), StructurePart::from(part));
-        let source = Source::new(os.clone(), source_url);
+    fn parse_import_command(
+        os: &OS,
+        part: &str,
+        names: &str,
+        source_url: &str,
+        is_dry_run: bool,
+    ) -> Self {
+        let part = match parse_part(part, "import") {
+            Ok(part) => part,
+            Err(err) => return Self::Unknown(err),
+        };
 
-        let name_list: Vec<String> = parse_comma_separated(names)
-            .into_iter()
-            .map(|n| n.trim().to_string())
-            .filter(|n| !n.is_empty())
-            .collect();
+        let name_list = parse_names(names);
         if name_list.is_empty() {
-            return Self::Unknown;
+            return Self::Unknown("No names were given for `import`".to_string());
+        }
+
+        if source_url.is_empty() {
+            return Self::Unknown("No source was given for `import`".to_string());
         }
 
+        let mut partial = StructurePartial::new(StructureVersion::default(), part);
+
+        partial.is_dry_run = is_dry_run;
+        let source = Source::new(os.clone(), source_url);
+
         Self::Import(partial, source, name_list)
     }
 }
 
+pub fn find_spec(name: &str) -> Option<&'static Spec> {
+    if LANG_NAMES.contains(&name) {
+        return Some(&GENERATOR_SPEC);
+    }
+
+    SPECS.iter().copied().find(|spec| spec.name == name)
+}
+
+fn known_flags() -> Vec<Flag> {
+    SPECS
+        .iter()
+        .chain([&&GENERATOR_SPEC])
+        .flat_map(|spec| spec.flags.iter())
+        .chain(GLOBAL_FLAGS)
+        .copied()
+        .collect()
+}
+
+fn unknown_command_message(name: &str) -> String {
+    let candidates = SPECS
+        .iter()
+        .map(|spec| spec.name)
+        .chain(LANG_NAMES.iter().copied());
+
+    match closest_match(name, candidates) {
+        Some(suggestion) => format!("Unknown command `{}`, did you mean `{}`", name, suggestion),
+        None => format!("Unknown command `{}`", name),
+    }
+}
+
+fn unknown_version_message(version: &str) -> String {
+    let available: Vec<&str> = StructureVersion::ALL.iter().map(|v| v.name()).collect();
+
+    format!(
+        "Unknown structure version `{}`, available versions: {}",
+        version,
+        available.join(", ")
+    )
+}
+
+fn unknown_output_message(format: &str) -> String {
+    format!(
+        "Unknown output format `{}`, available formats: {}",
+        format,
+        Output::NAMES.join(", ")
+    )
+}
+
+fn parse_part(part: &str, context: &str) -> Result<StructurePart, String> {
+    if let Some(part) = StructurePart::from_name(part) {
+        return Ok(part);
+    }
+
+    let names = StructurePart::ALL.iter().map(|p| p.name());
+
+    Err(match closest_match(part, names) {
+        Some(suggestion) => format!(
+            "Unknown part `{}` for `{}`, did you mean `{}`",
+            part, context, suggestion
+        ),
+        None => format!(
+            "Unknown part `{}` for `{}`, expected common, domain or feature",
+            part, context
+        ),
+    })
+}
+
+fn parse_names(names: &str) -> Vec<String> {
+    parse_comma_separated(names)
+        .into_iter()
+        .map(|n| n.trim().to_string())
+        .filter(|n| !n.is_empty())
+        .collect()
+}
+
 #[inline]
 fn parse_comma_separated(input: &str) -> Vec<String> {
     split_text(input, ",")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_command(raw_args: &[&str]) -> Command {
+        let raw_args: Vec<String> = raw_args.iter().map(|arg| arg.to_string()).collect();
+        Command::new(OS::new(), &raw_args)
+    }
+
+    fn unknown_message(raw_args: &[&str]) -> String {
+        match new_command(raw_args) {
+            Command::Unknown(message) => message,
+            _ => panic!("`{}` was accepted", raw_args.join(" ")),
+        }
+    }
+
+    #[test]
+    fn suggests_the_closest_command() {
+        assert_eq!(
+            unknown_message(&["gp"]),
+            "Unknown command `gp`, did you mean `go`"
+        );
+        assert_eq!(
+            unknown_message(&["help", "imprt"]),
+            "Unknown command `imprt`, did you mean `import`"
+        );
+    }
+
+    #[test]
+    fn suggests_the_closest_flag_of_the_command() {
+        assert_eq!(
+            unknown_message(&["go", "--nmae", "api"]),
+            "Unknown option `--nmae` for `go`, did you mean `--name`"
+        );
+        assert_eq!(
+            unknown_message(&["import", "--db", "mysql"]),
+            "Unknown option `--db` for `import`"
+        );
+    }
+
+    #[test]
+    fn checks_the_positional_arguments_of_the_spec() {
+        let positionals =
+            |args: &[&str]| -> Vec<String> { args.iter().map(|arg| arg.to_string()).collect() };
+
+        assert_eq!(
+            IMPORT_SPEC
+                .ensure_args("import", &positionals(&["feature"]))
+                .unwrap_err(),
+            "Missing NAMES for `import`"
+        );
+        assert_eq!(
+            IMPORT_SPEC
+                .ensure_args("import", &positionals(&["feature", "user", ".", "extra"]))
+                .unwrap_err(),
+            "Unexpected argument `extra` for `import`"
+        );
+        assert!(IMPORT_SPEC
+            .ensure_args("import", &positionals(&["feature", "user", "."]))
+            .is_ok());
+        assert!(HELP_SPEC.ensure_args("help", &[]).is_ok());
+    }
+
+    #[test]
+    fn suggests_the_closest_part() {
+        assert_eq!(
+            parse_part("featur", "add").unwrap_err(),
+            "Unknown part `featur` for `add`, did you mean `feature`"
+        );
+        assert_eq!(
+            parse_part("x", "import").unwrap_err(),
+            "Unknown part `x` for `import`, expected common, domain or feature"
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_shells() {
+        assert!(unknown_message(&["completions", "tcsh"]).starts_with("Unknown shell `tcsh`"));
+    }
+
+    #[test]
+    fn parses_generator_flags_into_the_preset() {
+        let Command::Generator(structure) =
+            new_command(&["go", "--name=api", "--dry-run", "./api"])
+        else {
+            panic!("`go` was not parsed as a generator");
+        };
+
+        let preset = &structure.lang.preset;
+        assert_eq!(preset.project_name.as_deref(), Some("api"));
+        assert_eq!(preset.project_dir, Some(PathBuf::from("./api")));
+        assert!(structure.is_dry_run);
+    }
+
+    #[test]
+    fn treats_a_positional_that_is_not_a_version_as_the_dir() {
+        let Command::Generator(structure) = new_command(&["go", "myproj", "--dry-run"]) else {
+            panic!("`go myproj` was not parsed as a generator");
+        };
+        assert_eq!(
+            structure.lang.preset.project_dir,
+            Some(PathBuf::from("myproj"))
+        );
+
+        let Command::Generator(structure) = new_command(&["go", "v4", "--dry-run"]) else {
+            panic!("`go v4` was not parsed as a generator");
+        };
+        assert_eq!(structure.lang.preset.project_dir, None);
+        assert_eq!(structure.version.name(), "v4");
+    }
+}
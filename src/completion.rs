@@ -0,0 +1,423 @@
+use std::path::Path;
+
+use crate::command::{find_spec, Spec, GENERATOR_SPEC, GLOBAL_FLAGS, SPECS};
+use crate::lang::{Database, Lang, NodePackageManager, PythonToolchain, NAMES as LANG_NAMES};
+use crate::os::OS;
+use crate::print::Output;
+use crate::structure::{Part as StructurePart, Version as StructureVersion};
+use crate::sys::{file_stem, filename};
+
+pub const COMPLETE_ARG: &str = "--complete";
+
+const NAMES_SEPARATOR: char = ',';
+
+const BASH_SCRIPT: &str = r#"_uwais() {
+    local IFS=$'\n'
+    COMPREPLY=($(uwais --complete "$COMP_POINT" "$COMP_LINE" 2>/dev/null))
+}
+
+complete -o default -F _uwais uwais
+"#;
+
+const ZSH_SCRIPT: &str = r#"#compdef uwais
+
+_uwais() {
+    local -a candidates
+    local before="${(j: :)words[1,CURRENT]}"
+    local point=$(( ${#before} - ${#SUFFIX} ))
+    candidates=("${(@f)$(uwais --complete $point "${(j: :)words}" 2>/dev/null)}")
+
+    if [[ -n "${candidates[1]}" ]]; then
+        compadd -- "${candidates[@]}"
+    else
+        _files
+    fi
+}
+
+if [ "$funcstack[1]" = "_uwais" ]; then
+    _uwais "$@"
+else
+    compdef _uwais uwais
+fi
+"#;
+
+const FISH_SCRIPT: &str = r#"function __uwais_complete
+    set -l line (commandline -p | string collect)
+    set -l candidates (uwais --complete (commandline -pC) "$line" 2>/dev/null)
+
+    if test (count $candidates) -gt 0
+        printf '%s\n' $candidates
+    else
+        __fish_complete_path (commandline -ct)
+    end
+end
+
+complete -c uwais -f -a '(__uwais_complete)'
+"#;
+
+const POWERSHELL_SCRIPT: &str = r#"Register-ArgumentCompleter -Native -CommandName uwais -ScriptBlock {
+    param($wordToComplete, $commandAst, $cursorPosition)
+
+    $line = $commandAst.Extent.Text
+    $point = $cursorPosition - $commandAst.Extent.StartOffset
+
+    uwais --complete $point $line 2>$null | ForEach-Object {
+        [System.Management.Automation.CompletionResult]::new($_, $_, 'ParameterValue', $_)
+    }
+}
+"#;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shell {
+    Bash,
+    Zsh,
+    Fish,
+    Powershell,
+}
+
+impl Shell {
+    pub const NAMES: &'static [&'static str] = &["bash", "zsh", "fish", "powershell"];
+
+    #[inline]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "bash" => Some(Shell::Bash),
+            "zsh" => Some(Shell::Zsh),
+            "fish" => Some(Shell::Fish),
+            "powershell" | "pwsh" => Some(Shell::Powershell),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub const fn script(self) -> &'static str {
+        match self {
+            Shell::Bash => BASH_SCRIPT,
+            Shell::Zsh => ZSH_SCRIPT,
+            Shell::Fish => FISH_SCRIPT,
+            Shell::Powershell => POWERSHELL_SCRIPT,
+        }
+    }
+}
+
+pub fn complete(os: OS, line: &str, point: usize) -> Vec<String> {
+    let mut before: String = line.chars().take(point).collect();
+    if point > before.chars().count() {
+        before.push(' ');
+    }
+    let after: String = line.chars().skip(point).collect();
+
+    let (mut words, is_in_word) = split_line(&before);
+    if !is_in_word {
+        words.push(String::new());
+    }
+    let index = words.len() - 1;
+
+    let (after_words, _) = split_line(&after);
+    let is_word_continued = after.starts_with(|c: char| !c.is_whitespace());
+    words.extend(after_words.into_iter().skip(usize::from(is_word_continued)));
+
+    complete_words(os, &words, index)
+}
+
+fn complete_words(os: OS, words: &[String], index: usize) -> Vec<String> {
+    let words = words.get(1..).unwrap_or_default();
+    let Some(index) = index.checked_sub(1) else {
+        return Vec::new();
+    };
+
+    let current = words.get(index).map(String::as_str).unwrap_or_default();
+    let (positionals, previous) = split_words(&words[..index.min(words.len())]);
+
+    let spec = positionals.first().and_then(|name| find_spec(name));
+
+    if let Some(flag) = previous {
+        return match flag {
+            "--output" => filter(Output::NAMES.iter().copied(), current),
+            "--http" => {
+                let name = positionals.first().copied().unwrap_or_default();
+                let frameworks = Lang::new(os, name).http_frameworks();
+
+                filter(frameworks.iter().map(|f| f.name()), current)
+            }
+            "--db" => {
+                let name = positionals.first().copied().unwrap_or_default();
+                let lang = Lang::new(os, name);
+                let databases = if lang.is_valid() {
+                    lang.databases()
+                } else {
+                    Database::ALL
+                };
+
+                filter(databases.iter().map(|d| d.name()), current)
+            }
+            "--python-tool" => filter(PythonToolchain::ALL.iter().map(|t| t.name()), current),
+            "--pm" => {
+                let name = positionals.first().copied().unwrap_or_default();
+                let lang = Lang::new(os, name);
+                let package_managers = if lang.is_valid() {
+                    lang.node_package_managers()
+                } else {
+                    NodePackageManager::ALL
+                };
+
+                filter(package_managers.iter().map(|pm| pm.name()), current)
+            }
+            _ => Vec::new(),
+        };
+    }
+
+    if current.starts_with('-') {
+        let flags = spec
+            .map(|spec| spec.flags)
+            .unwrap_or_default()
+            .iter()
+            .chain(GLOBAL_FLAGS)
+            .map(|flag| flag.long);
+
+        return filter(flags, current);
+    }
+
+    let Some(spec) = spec else {
+        return match positionals.len() {
+            0 => filter(command_candidates(), current),
+            _ => Vec::new(),
+        };
+    };
+
+    complete_arg(os, spec, &positionals, words, current)
+}
+
+fn complete_arg(
+    os: OS,
+    spec: &Spec,
+    positionals: &[&str],
+    words: &[String],
+    current: &str,
+) -> Vec<String> {
+    let position = positionals.len();
+
+    if spec.name == GENERATOR_SPEC.name {
+        return match position {
+            1 => filter(StructureVersion::ALL.iter().map(|v| v.name()), current),
+            _ => Vec::new(),
+        };
+    }
+
+    match (spec.name, position) {
+        ("help", 1) => filter(command_candidates(), current),
+        ("completions", 1) => filter(Shell::NAMES.iter().copied(), current),
+        ("add" | "import", 1) => filter(StructurePart::ALL.iter().map(|p| p.name()), current),
+        ("import", 2) => {
+            let (all_positionals, _) = split_words(words);
+            let (Some(part), Some(source)) = (all_positionals.get(1), all_positionals.get(3))
+            else {
+                return Vec::new();
+            };
+
+            complete_source_names(os, part, Path::new(source), current)
+        }
+        _ => Vec::new(),
+    }
+}
+
+fn complete_source_names(os: OS, part: &str, source_dir: &Path, current: &str) -> Vec<String> {
+    let Some(part) = StructurePart::from_name(part) else {
+        return Vec::new();
+    };
+
+    let lang = Lang::new_from_dir(os, source_dir);
+    if !lang.is_valid() {
+        return Vec::new();
+    }
+
+    let Ok(entries) = source_dir
+        .join(&lang.src_dir_name)
+        .join(part.dir_name())
+        .read_dir()
+    else {
+        return Vec::new();
+    };
+
+    let mut names: Vec<String> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| !lang.mod_file_names.contains(&filename(path)))
+        .map(|path| {
+            if path.is_dir() {
+                filename(&path)
+            } else {
+                file_stem(&path)
+            }
+        })
+        .filter(|name| !name.is_empty() && !name.starts_with('.'))
+        .collect();
+
+    names.sort();
+    names.dedup();
+
+    let (given, last) = match current.rfind(NAMES_SEPARATOR) {
+        Some(i) => current.split_at(i + 1),
+        None => ("", current),
+    };
+
+    names
+        .iter()
+        .filter(|name| name.starts_with(last))
+        .map(|name| format!("{}{}", given, name))
+        .collect()
+}
+
+fn split_line(line: &str) -> (Vec<String>, bool) {
+    let mut words = Vec::new();
+    let mut word: Option<String> = None;
+    let mut quote = None;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), c) => word.get_or_insert_with(String::new).push(c),
+            (None, '\'' | '"') => {
+                quote = Some(c);
+                word.get_or_insert_with(String::new);
+            }
+            (None, '\\') => {
+                let escaped = chars.next_if(|n| n.is_whitespace() || *n == '\\');
+                word.get_or_insert_with(String::new)
+                    .push(escaped.unwrap_or(c));
+            }
+            (None, c) if c.is_whitespace() => words.extend(word.take()),
+            (None, c) => word.get_or_insert_with(String::new).push(c),
+        }
+    }
+
+    let is_in_word = word.is_some();
+    words.extend(word);
+
+    (words, is_in_word)
+}
+
+fn split_words(words: &[String]) -> (Vec<&str>, Option<&str>) {
+    let known_flags = SPECS
+        .iter()
+        .chain([&&GENERATOR_SPEC])
+        .flat_map(|spec| spec.flags.iter())
+        .chain(GLOBAL_FLAGS)
+        .collect::<Vec<_>>();
+
+    let mut positionals = Vec::new();
+    let mut pending_flag = None;
+
+    for word in words {
+        if pending_flag.take().is_some() {
+            continue;
+        }
+
+        if !word.starts_with('-') {
+            positionals.push(word.as_str());
+            continue;
+        }
+
+        pending_flag = known_flags
+            .iter()
+            .find(|flag| flag.long == word || flag.short == Some(word.as_str()))
+            .filter(|flag| flag.value.is_some())
+            .map(|flag| flag.long);
+    }
+
+    (positionals, pending_flag)
+}
+
+#[inline]
+fn command_candidates() -> impl Iterator<Item = &'static str> {
+    SPECS
+        .iter()
+        .map(|spec| spec.name)
+        .chain(LANG_NAMES.iter().copied())
+}
+
+fn filter<'a, I>(candidates: I, current: &str) -> Vec<String>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    candidates
+        .into_iter()
+        .filter(|candidate| candidate.starts_with(current))
+        .map(String::from)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sys::{create_dir, path_to_str, remove_dir, write_file};
+    use std::env::temp_dir;
+    use std::path::PathBuf;
+    use std::process::id;
+
+    fn create_source_project() -> PathBuf {
+        let dir = temp_dir().join(format!("uwais-completion-{}", id()));
+        let _ = remove_dir(&dir);
+        create_dir(dir.join("domain")).unwrap();
+        write_file(dir.join("main.go"), "package main\n").unwrap();
+        write_file(dir.join("domain").join("user.go"), "package domain\n").unwrap();
+        write_file(dir.join("domain").join("product.go"), "package domain\n").unwrap();
+
+        dir
+    }
+
+    fn complete_at(line: &str) -> Vec<String> {
+        let point = line.find('|').unwrap();
+
+        complete(OS::new(), &line.replacen('|', "", 1), point)
+    }
+
+    #[test]
+    fn splits_line_with_quotes_and_escapes() {
+        assert_eq!(
+            split_line(r#"uwais import "a b" c\ d 'e' "#),
+            (
+                vec!["uwais", "import", "a b", "c d", "e"]
+                    .into_iter()
+                    .map(String::from)
+                    .collect(),
+                false
+            )
+        );
+        assert_eq!(
+            split_line("uwais im"),
+            (vec!["uwais".to_string(), "im".to_string()], true)
+        );
+    }
+
+    #[test]
+    fn completes_names_before_source() {
+        let dir = create_source_project();
+        let source = path_to_str(&dir);
+
+        assert_eq!(
+            complete_at(&format!("uwais import domain | {}", source)),
+            vec!["product", "user"]
+        );
+        assert_eq!(
+            complete_at(&format!("uwais import domain user,p| {}", source)),
+            vec!["user,product"]
+        );
+        assert_eq!(
+            complete_at(&format!("uwais import domain u|se '{}'", source)),
+            vec!["user"]
+        );
+        assert!(complete_at("uwais import domain |").is_empty());
+
+        let _ = remove_dir(&dir);
+    }
+
+    #[test]
+    fn completes_past_the_end_of_line() {
+        let line = "uwais go";
+
+        assert_eq!(complete(OS::new(), line, line.len() + 1), vec!["v4"]);
+        assert_eq!(complete(OS::new(), line, line.len()), vec!["go"]);
+    }
+}
@@ -0,0 +1,209 @@
+use std::error::Error as StdError;
+use std::fmt;
+use std::io::{Error as IoError, ErrorKind as IoErrorKind};
+use std::path::PathBuf;
+use std::process::ExitStatus;
+
+use zip::result::ZipError;
+
+type BoxedError = Box<dyn StdError + Send + Sync>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    NotFound,
+    AlreadyExists,
+    PermissionDenied,
+    Network,
+    Command,
+    InvalidInput,
+    Other,
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Io { context: String, source: IoError },
+    NotFound(PathBuf),
+    AlreadyExists(PathBuf),
+    NotADirectory(PathBuf),
+    Archive { path: PathBuf, source: ZipError },
+    Network { url: String, source: BoxedError },
+    HttpStatus { url: String, status: u16 },
+    InvalidResponse { url: String, source: BoxedError },
+    CommandUnavailable(String),
+    CommandSpawn { command: String, source: IoError },
+    CommandFailed { command: String, status: ExitStatus },
+    InvalidInput(String),
+    Message(String),
+    Context { context: String, source: Box<Error> },
+}
+
+impl Error {
+    #[inline]
+    pub fn io<C: Into<String>>(context: C, source: IoError) -> Self {
+        Error::Io {
+            context: context.into(),
+            source,
+        }
+    }
+
+    #[inline]
+    pub fn context<C: Into<String>>(self, context: C) -> Self {
+        Error::Context {
+            context: context.into(),
+            source: Box::new(self),
+        }
+    }
+
+    pub fn kind(&self) -> Kind {
+        match self {
+            Error::Io { source, .. } | Error::CommandSpawn { source, .. } => match source.kind() {
+                IoErrorKind::NotFound => Kind::NotFound,
+                IoErrorKind::AlreadyExists => Kind::AlreadyExists,
+                IoErrorKind::PermissionDenied => Kind::PermissionDenied,
+                _ if matches!(self, Error::CommandSpawn { .. }) => Kind::Command,
+                _ => Kind::Other,
+            },
+            Error::NotFound(_) | Error::CommandUnavailable(_) => Kind::NotFound,
+            Error::AlreadyExists(_) | Error::NotADirectory(_) => Kind::AlreadyExists,
+            Error::Archive { .. } | Error::Message(_) => Kind::Other,
+            Error::Network { .. } | Error::HttpStatus { .. } | Error::InvalidResponse { .. } => {
+                Kind::Network
+            }
+            Error::CommandFailed { .. } => Kind::Command,
+            Error::InvalidInput(_) => Kind::InvalidInput,
+            Error::Context { source, .. } => source.kind(),
+        }
+    }
+
+    #[inline]
+    pub fn is_permission_denied(&self) -> bool {
+        self.kind() == Kind::PermissionDenied
+    }
+
+    #[inline]
+    pub fn is_conflict(&self) -> bool {
+        self.kind() == Kind::AlreadyExists
+    }
+
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Error::Network { .. } | Error::CommandFailed { .. } => true,
+            Error::HttpStatus { status, .. } => *status == 429 || *status >= 500,
+            Error::Context { source, .. } => source.is_retryable(),
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io { context, source } => write!(f, "{}: {}", context, source),
+            Error::NotFound(path) => write!(f, "Path does not exist: {}", path.display()),
+            Error::AlreadyExists(path) => write!(f, "Path already exists: {}", path.display()),
+            Error::NotADirectory(path) => {
+                write!(f, "Path exists but is not a directory: {}", path.display())
+            }
+            Error::Archive { path, source } => {
+                write!(f, "Failed to extract {}: {}", path.display(), source)
+            }
+            Error::Network { url, source } => {
+                write!(f, "Network error while requesting {}: {}", url, source)
+            }
+            Error::HttpStatus { url, status } => write!(
+                f,
+                "HTTP request to {} failed with status code {}",
+                url, status
+            ),
+            Error::InvalidResponse { url, source } => {
+                write!(f, "Invalid response from {}: {}", url, source)
+            }
+            Error::CommandUnavailable(command) => write!(
+                f,
+                "`{}` is not installed or is not available in PATH",
+                command
+            ),
+            Error::CommandSpawn { command, source } => {
+                write!(f, "Failed to run `{}`: {}", command, source)
+            }
+            Error::CommandFailed { command, status } => match status.code() {
+                Some(code) => write!(f, "`{}` exited with status code {}", command, code),
+                None => write!(f, "`{}` was terminated by a signal", command),
+            },
+            Error::InvalidInput(message) | Error::Message(message) => f.write_str(message),
+            Error::Context { context, source } => write!(f, "{}: {}", context, source),
+        }
+    }
+}
+
+impl StdError for Error {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Error::Io { source, .. } | Error::CommandSpawn { source, .. } => Some(source),
+            Error::Archive { source, .. } => Some(source),
+            Error::Network { source, .. } | Error::InvalidResponse { source, .. } => {
+                Some(source.as_ref())
+            }
+            Error::Context { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+pub trait Context<T> {
+    fn context<C: Into<String>>(self, context: C) -> Result<T, Error>;
+}
+
+impl<T> Context<T> for Result<T, Error> {
+    #[inline]
+    fn context<C: Into<String>>(self, context: C) -> Result<T, Error> {
+        self.map_err(|err| err.context(context))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_io_errors_to_kinds() {
+        let err = Error::io("read", IoError::from(IoErrorKind::NotFound));
+        assert_eq!(err.kind(), Kind::NotFound);
+
+        let err = Error::io("write", IoError::from(IoErrorKind::PermissionDenied));
+        assert!(err.is_permission_denied());
+
+        let err = Error::CommandSpawn {
+            command: "go".to_string(),
+            source: IoError::from(IoErrorKind::Interrupted),
+        };
+        assert_eq!(err.kind(), Kind::Command);
+    }
+
+    #[test]
+    fn treats_existing_paths_as_conflicts() {
+        assert!(Error::AlreadyExists(PathBuf::from("a")).is_conflict());
+        assert!(Error::NotADirectory(PathBuf::from("a")).is_conflict());
+        assert!(!Error::NotFound(PathBuf::from("a")).is_conflict());
+    }
+
+    #[test]
+    fn keeps_the_kind_and_message_through_context() {
+        let err = Error::AlreadyExists(PathBuf::from("api")).context("Failed to stage");
+        assert!(err.is_conflict());
+        assert_eq!(err.to_string(), "Failed to stage: Path already exists: api");
+    }
+
+    #[test]
+    fn retries_network_and_server_errors_only() {
+        let status = |status| Error::HttpStatus {
+            url: "https://example.com".to_string(),
+            status,
+        };
+        assert!(status(429).is_retryable());
+        assert!(status(503).is_retryable());
+        assert!(!status(404).is_retryable());
+        assert!(status(502).context("Failed to download").is_retryable());
+        assert!(!Error::InvalidInput("bad".to_string()).is_retryable());
+    }
+}
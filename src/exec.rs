@@ -1,59 +1,181 @@
-use colored::{ColoredString, Colorize};
+use std::fs::File;
+use std::io::{stderr, stdout, Write};
 use std::path::{Path, PathBuf};
 use std::process::{Child, Command, ExitStatus, Stdio};
+use std::time::Instant;
 
+use crate::error::Error;
+use crate::print::{is_json_output, is_quiet, is_verbose, print_verbose};
 use crate::sys::cwd;
 
 const VERSION_FLAG: &str = "--version";
 const VERSION_CMD: &str = "version";
 const V_FLAG: &str = "-v";
 
+// Written like `>` in a shell: the next and last argument is the file that receives stdout.
+pub const STDOUT_REDIRECT: &str = "{{STDOUT_REDIRECT}}";
+
 #[inline]
-pub fn exec<P: AsRef<Path>>(current_dir: P, command: &[&str]) -> Result<ExitStatus, String> {
+pub fn exec<P: AsRef<Path>>(current_dir: P, command: &[&str]) -> Result<ExitStatus, Error> {
     prepare_command(current_dir, command)?
-        .stdout(Stdio::inherit())
+        .stdout(inherited_stdout())
         .stderr(Stdio::inherit())
         .status()
-        .map_err(|err| err.to_string())
+        .map_err(|err| spawn_error(command, err))
 }
 
 #[inline]
 pub fn exec_no_std_out<P: AsRef<Path>>(
     current_dir: P,
     command: &[&str],
-) -> Result<ExitStatus, String> {
+) -> Result<ExitStatus, Error> {
     prepare_command(current_dir, command)?
         .stdout(Stdio::null())
         .stderr(Stdio::null())
         .status()
-        .map_err(|err| err.to_string())
+        .map_err(|err| spawn_error(command, err))
 }
 
 #[inline]
-pub fn exec_spawn<P: AsRef<Path>>(current_dir: P, command: &[&str]) -> Result<Child, String> {
+pub fn exec_spawn<P: AsRef<Path>>(current_dir: P, command: &[&str]) -> Result<Child, Error> {
     prepare_command(current_dir, command)?
         .spawn()
-        .map_err(|err| err.to_string())
+        .map_err(|err| spawn_error(command, err))
 }
 
 pub fn exec_vec_string_command<P: AsRef<Path>>(
     current_dir: P,
     command: &[String],
-) -> Result<ExitStatus, String> {
+) -> Result<ExitStatus, Error> {
     if command.is_empty() {
-        return Err("The command array is empty".to_string());
+        return Err(Error::InvalidInput(
+            "The command array is empty".to_string(),
+        ));
+    }
+
+    let mut command_ref: Vec<&str> = command.iter().map(|s| s.as_str()).collect();
+    let stdout_path = match command_ref.iter().position(|arg| *arg == STDOUT_REDIRECT) {
+        Some(index) if index > 0 && index + 2 == command_ref.len() => {
+            let path = command_ref[index + 1];
+            command_ref.truncate(index);
+            Some(resolve_command_dir(&current_dir).join(path))
+        }
+        Some(_) => {
+            return Err(Error::InvalidInput(format!(
+                "The output redirect of `{}` must be followed by exactly one file",
+                join_command(command)
+            )))
+        }
+        None => None,
+    };
+    let command_line = format_command_line(&command_ref);
+    let started_at = Instant::now();
+
+    if is_verbose() {
+        print_verbose(&format!(
+            "Running `{}` in {}",
+            command_line,
+            resolve_command_dir(&current_dir).display()
+        ));
+    }
+
+    let status = if let Some(path) = stdout_path {
+        exec_redirected(current_dir, &command_ref, &path)?
+    } else if is_quiet() {
+        exec_captured(current_dir, &command_ref)?
+    } else {
+        exec(current_dir, &command_ref)?
+    };
+
+    if is_verbose() {
+        print_verbose(&format!(
+            "Finished `{}` in {:.2?} ({})",
+            command_line,
+            started_at.elapsed(),
+            status
+        ));
     }
 
-    let command_ref: Vec<&str> = command.iter().map(|s| s.as_str()).collect();
+    if !status.success() {
+        return Err(Error::CommandFailed {
+            command: join_command(command),
+            status,
+        });
+    }
 
-    exec(current_dir, &command_ref)
+    Ok(status)
+}
+
+pub fn join_command(command: &[String]) -> String {
+    command
+        .iter()
+        .map(|arg| {
+            if arg == STDOUT_REDIRECT {
+                ">"
+            } else {
+                arg.as_str()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn exec_redirected<P: AsRef<Path>>(
+    current_dir: P,
+    command: &[&str],
+    stdout_path: &Path,
+) -> Result<ExitStatus, Error> {
+    let file = File::create(stdout_path)
+        .map_err(|err| Error::io(format!("Failed to create `{}`", stdout_path.display()), err))?;
+
+    prepare_command(current_dir, command)?
+        .stdout(file)
+        .stderr(Stdio::inherit())
+        .status()
+        .map_err(|err| spawn_error(command, err))
+}
+
+fn exec_captured<P: AsRef<Path>>(current_dir: P, command: &[&str]) -> Result<ExitStatus, Error> {
+    let output = prepare_command(current_dir, command)?
+        .output()
+        .map_err(|err| spawn_error(command, err))?;
+
+    if !output.status.success() {
+        if is_json_output() {
+            let _ = stderr().write_all(&output.stdout);
+        } else {
+            let _ = stdout().write_all(&output.stdout);
+        }
+        let _ = stderr().write_all(&output.stderr);
+    }
+
+    Ok(output.status)
 }
 
 #[inline]
-pub fn vec_string_command_to_colored_string(commands: &[String]) -> ColoredString {
-    commands.join(" ").bright_cyan()
+fn inherited_stdout() -> Stdio {
+    if is_json_output() {
+        Stdio::from(stderr())
+    } else {
+        Stdio::inherit()
+    }
+}
+
+fn format_command_line(command: &[&str]) -> String {
+    command
+        .iter()
+        .map(|arg| {
+            if arg.is_empty() || arg.contains(char::is_whitespace) {
+                format!("{:?}", arg)
+            } else {
+                arg.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
 }
 
+#[inline]
 pub fn check_command<P: AsRef<Path>>(command_dir: P, command: &str) -> bool {
     if command.trim().is_empty() {
         return false;
@@ -73,11 +195,14 @@ fn get_command_check_args(command: &str) -> Option<Vec<&str>> {
     let base_cmd = extract_base_command(command);
     match base_cmd.as_str() {
         "python" | "python3" | "py" | "git" => Some(vec![command, VERSION_FLAG]),
-        "pip" | "pip3" => Some(vec![command, VERSION_FLAG]),
+        "pip" | "pip3" | "uv" | "poetry" => Some(vec![command, VERSION_FLAG]),
         "npm" | "npx" => Some(vec![command, V_FLAG]),
+        "pnpm" | "yarn" | "bun" | "bunx" => Some(vec![command, VERSION_FLAG]),
         "node" | "cargo" => Some(vec![command, VERSION_FLAG]),
         "go" => Some(vec![command, VERSION_CMD]),
-        "npx tsc" | "tsc" | "npx tsc-alias" | "tsc-alias" => Some(vec![command, VERSION_FLAG]),
+        "java" | "gradle" | "gradlew" | "mvn" | "mvnw" => Some(vec![command, VERSION_FLAG]),
+        "dotnet" | "php" | "composer" => Some(vec![command, VERSION_FLAG]),
+        "tsc" | "tsc-alias" => Some(vec![command, VERSION_FLAG]),
         _ => None,
     }
 }
@@ -95,6 +220,8 @@ fn extract_base_command(command: &str) -> String {
         base = base.trim_end_matches(".cmd");
     } else if base.ends_with(".ps1") {
         base = base.trim_end_matches(".ps1");
+    } else if base.ends_with(".bat") {
+        base = base.trim_end_matches(".bat");
     }
 
     base.to_string()
@@ -115,36 +242,47 @@ fn resolve_command_path<P: AsRef<Path>>(current_dir: P, cmd_name: &Path) -> std:
     cmd_name.to_path_buf()
 }
 
-fn prepare_command<P: AsRef<Path>>(current_dir: P, command: &[&str]) -> Result<Command, String> {
+#[inline]
+fn spawn_error(command: &[&str], source: std::io::Error) -> Error {
+    Error::CommandSpawn {
+        command: command.join(" "),
+        source,
+    }
+}
+
+fn resolve_command_dir<P: AsRef<Path>>(current_dir: P) -> PathBuf {
+    let current_dir = current_dir.as_ref();
+    let command_dir = if current_dir.is_dir() {
+        current_dir.to_path_buf()
+    } else {
+        cwd()
+    };
+
+    let canonical = command_dir.canonicalize().unwrap_or(command_dir);
+    let path_str = canonical.to_string_lossy();
+    match path_str.strip_prefix(r"\\?\") {
+        Some(stripped) => PathBuf::from(stripped),
+        None => canonical,
+    }
+}
+
+fn prepare_command<P: AsRef<Path>>(current_dir: P, command: &[&str]) -> Result<Command, Error> {
     if command.is_empty() {
-        return Err("The command array is empty".to_string());
+        return Err(Error::InvalidInput(
+            "The command array is empty".to_string(),
+        ));
     }
 
     let raw_cmd = command[0].trim();
     if raw_cmd.is_empty() {
-        return Err("The command name is empty".to_string());
+        return Err(Error::InvalidInput("The command name is empty".to_string()));
     }
 
     let cmd_path = Path::new(raw_cmd)
         .components()
         .collect::<std::path::PathBuf>();
 
-    let current_dir = current_dir.as_ref();
-    let command_dir = if current_dir.is_dir() {
-        current_dir.to_path_buf()
-    } else {
-        cwd()
-    };
-
-    let canonical = command_dir.canonicalize().unwrap_or(command_dir);
-    let final_dir = {
-        let path_str = canonical.to_string_lossy();
-        if path_str.starts_with(r"\\?\") {
-            PathBuf::from(&path_str[4..])
-        } else {
-            canonical
-        }
-    };
+    let final_dir = resolve_command_dir(current_dir);
 
     let resolved_cmd = resolve_command_path(&final_dir, &cmd_path);
     let mut cmd = Command::new(resolved_cmd);
@@ -157,3 +295,35 @@ fn prepare_command<P: AsRef<Path>>(current_dir: P, command: &[&str]) -> Result<C
 
     Ok(cmd)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sys::{create_dir, read_file, remove_dir};
+    use std::env::temp_dir;
+    use std::process::id;
+
+    #[test]
+    fn checks_only_the_package_manager_binary() {
+        for command in ["pnpm", "pnpm.cmd", "yarn.ps1", "bunx.exe", "npx"] {
+            assert!(get_command_check_args(command).is_some());
+        }
+        assert!(get_command_check_args("pnpm exec").is_none());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn writes_redirected_stdout_to_the_file() {
+        let dir = temp_dir().join(format!("uwais-redirect-{}", id()));
+        create_dir(&dir).unwrap();
+        let command = ["echo", "a > b", STDOUT_REDIRECT, "out.txt"].map(String::from);
+
+        let result = exec_vec_string_command(&dir, &command);
+        let content = read_file(dir.join("out.txt"));
+        let _ = remove_dir(&dir);
+
+        assert!(result.is_ok());
+        assert_eq!(content.unwrap(), "a > b\n");
+        assert_eq!(join_command(&command), "echo a > b > out.txt");
+    }
+}
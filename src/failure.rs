@@ -0,0 +1,63 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Failure {
+    UnsupportedOS,
+    InvalidArguments,
+    InvalidProject,
+    SourceUnavailable,
+    Filesystem,
+    ExternalCommand,
+}
+
+impl Failure {
+    pub const ALL: &'static [Failure] = &[
+        Failure::UnsupportedOS,
+        Failure::InvalidArguments,
+        Failure::InvalidProject,
+        Failure::SourceUnavailable,
+        Failure::Filesystem,
+        Failure::ExternalCommand,
+    ];
+
+    #[inline]
+    pub const fn code(self) -> i32 {
+        match self {
+            Failure::UnsupportedOS => 1,
+            Failure::InvalidArguments => 2,
+            Failure::InvalidProject => 3,
+            Failure::SourceUnavailable => 4,
+            Failure::Filesystem => 5,
+            Failure::ExternalCommand => 6,
+        }
+    }
+
+    #[inline]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Failure::UnsupportedOS => "unsupported OS",
+            Failure::InvalidArguments => "invalid arguments",
+            Failure::InvalidProject => "invalid project",
+            Failure::SourceUnavailable => "source unavailable",
+            Failure::Filesystem => "filesystem error",
+            Failure::ExternalCommand => "external command failed",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_failures_to_distinct_exit_codes() {
+        let codes: Vec<i32> = Failure::ALL.iter().map(|failure| failure.code()).collect();
+        assert_eq!(codes, vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn names_every_failure() {
+        assert_eq!(Failure::InvalidArguments.name(), "invalid arguments");
+        assert!(Failure::ALL
+            .iter()
+            .all(|failure| !failure.name().is_empty()));
+    }
+}
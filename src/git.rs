@@ -1,11 +1,13 @@
 use std::path::Path;
 
-use crate::exec::{check_command, exec};
+use crate::error::{Context, Error};
+use crate::exec::{check_command, exec, exec_no_std_out};
 use crate::http::download_file;
-use crate::sys::{cwd, dirname, extract_archive, path_to_colored};
+use crate::sys::{cwd, dirname, extract_archive};
 
 const GITHUB_DOMAIN: &str = "github.com";
 const DEFAULT_BRANCHES: &[&str] = &["master", "main"];
+const DEFAULT_COMMIT_MESSAGE: &str = "Initial commit";
 
 #[inline]
 pub fn is_git_common_url(url: &str) -> bool {
@@ -22,41 +24,84 @@ pub fn is_valid_git_url(url: &str) -> bool {
     is_git_common_url(url) || is_git_ssh_url(url)
 }
 
-pub fn git_clone<P: AsRef<Path>>(url: &str, output_dir: P) -> Result<(), String> {
+#[inline]
+pub fn is_git_work_tree<P: AsRef<Path>>(dir: P) -> bool {
+    let dir = dir.as_ref();
+
+    dir.is_dir()
+        && exec_no_std_out(dir, &["git", "rev-parse", "--is-inside-work-tree"])
+            .is_ok_and(|status| status.success())
+}
+
+pub fn parse_git_author(author: &str) -> Option<(&str, &str)> {
+    let (name, email) = author.trim().strip_suffix('>')?.split_once('<')?;
+    let (name, email) = (name.trim(), email.trim());
+
+    (!name.is_empty() && email.contains('@')).then_some((name, email))
+}
+
+pub fn git_init_commands(message: Option<&str>, author: Option<&str>) -> Vec<Vec<String>> {
+    let mut commit = vec!["git".to_string()];
+
+    if let Some((name, email)) = author.and_then(parse_git_author) {
+        commit.extend([
+            "-c".to_string(),
+            format!("user.name={}", name),
+            "-c".to_string(),
+            format!("user.email={}", email),
+        ]);
+    }
+    commit.extend([
+        "commit".to_string(),
+        "-m".to_string(),
+        message.unwrap_or(DEFAULT_COMMIT_MESSAGE).to_string(),
+    ]);
+
+    vec![
+        vec!["git".to_string(), "init".to_string()],
+        vec!["git".to_string(), "add".to_string(), "-A".to_string()],
+        commit,
+    ]
+}
+
+pub fn git_clone<P: AsRef<Path>>(url: &str, output_dir: P) -> Result<(), Error> {
     if !is_valid_git_url(url) {
-        return Err(format!("Invalid Git URL: {}", url));
+        return Err(Error::InvalidInput(format!("Invalid Git URL: {}", url)));
     }
 
     let output_dir = output_dir.as_ref();
     let output_dir_name = dirname(output_dir);
 
     if output_dir_name.is_empty() {
-        return Err("Git repository output path cannot be empty".to_string());
+        return Err(Error::InvalidInput(
+            "Git repository output path cannot be empty".to_string(),
+        ));
     }
 
     if !check_command(cwd(), "git") {
-        return Err("Git is not installed or is not available in PATH".to_string());
+        return Err(Error::CommandUnavailable("git".to_string()));
     }
 
-    exec(cwd(), &["git", "clone", url, &output_dir_name]).map_err(|err| {
-        format!(
-            "Failed to clone Git repository from {}: {}",
-            path_to_colored(url),
-            err
-        )
-    })?;
+    let command = ["git", "clone", url, &output_dir_name];
+    let context = format!("Failed to clone Git repository from {}", url);
+
+    let status = exec(cwd(), &command).context(context.as_str())?;
+    if !status.success() {
+        return Err(Error::CommandFailed {
+            command: command.join(" "),
+            status,
+        }
+        .context(context));
+    }
 
     if !output_dir.is_dir() {
-        return Err(format!(
-            "Git repository was not cloned successfully to {}",
-            path_to_colored(output_dir)
-        ));
+        return Err(Error::NotFound(output_dir.to_path_buf()).context(context));
     }
 
     Ok(())
 }
 
-pub fn git_download<P: AsRef<Path>>(url: &str, output_dir: P) -> Result<(), String> {
+pub fn git_download<P: AsRef<Path>>(url: &str, output_dir: P) -> Result<(), Error> {
     let output_dir = output_dir.as_ref();
 
     if let Ok(()) = try_archive_download(url, output_dir) {
@@ -66,9 +111,11 @@ pub fn git_download<P: AsRef<Path>>(url: &str, output_dir: P) -> Result<(), Stri
     git_clone(url, output_dir)
 }
 
-fn try_archive_download<P: AsRef<Path>>(url: &str, output_dir: P) -> Result<(), String> {
+fn try_archive_download<P: AsRef<Path>>(url: &str, output_dir: P) -> Result<(), Error> {
     if !url.contains(GITHUB_DOMAIN) {
-        return Err("Archive download is only supported for GitHub repositories".to_string());
+        return Err(Error::InvalidInput(
+            "Archive download is only supported for GitHub repositories".to_string(),
+        ));
     }
 
     let output_dir = output_dir.as_ref();
@@ -96,7 +143,9 @@ fn try_archive_download<P: AsRef<Path>>(url: &str, output_dir: P) -> Result<(),
         }
     }
 
-    Err("Failed to download and extract archive from all attempted branches".to_string())
+    Err(Error::Message(
+        "Failed to download and extract archive from all attempted branches".to_string(),
+    ))
 }
 
 fn format_github_archive_url(git_url: &str, branch: &str) -> String {
@@ -1,21 +1,45 @@
 use colored::Colorize;
 use serde_json::Value as Json;
 use std::path::{Path, PathBuf, MAIN_SEPARATOR_STR};
-use toml::Value as Toml;
+use toml::{Table as TomlTable, Value as Toml};
 
-use crate::exec::check_command;
+use crate::exec::{check_command, STDOUT_REDIRECT};
+use crate::git::parse_git_author;
 use crate::os::{Kind as OSKind, OS};
 use crate::read::read_input;
-use crate::string::join_text;
-use crate::sys::{cwd, dirname, is_dir_has, read_file};
+use crate::string::{join_text, to_pascal_case};
+use crate::sys::{cwd, dirname, file_stem, is_dir_has, read_file};
 
 pub static PACKAGE_NAME_PLACEHOLDER: &str = "{{PACKAGE_NAME}}";
 pub static MODULE_NAME_PLACEHOLDER: &str = "{{MODULE_NAME}}";
+pub const DEFAULT_PORT: u16 = 8080;
+const RUST_SCAFFOLD_MAIN: &str = "fn main() {\n    println!(\"Hello, world!\");\n}";
+const RUST_SCAFFOLD_GITIGNORE: &str = "/target";
+pub const NAMES: &[&str] = &[
+    "go",
+    "python",
+    "py",
+    "typescript",
+    "ts",
+    "nodejs",
+    "node",
+    "rust",
+    "rs",
+    "sveltekit",
+    "java",
+    "csharp",
+    "cs",
+    "php",
+];
 
 const PYTHON_COMMANDS: &[&str] = &["python", "python3"];
 const PYTHON_COMMANDS_WINDOWS: &[&str] = &["py", "python.exe", "python3.exe", "py.exe"];
 const PIP_COMMANDS: &[&str] = &["pip", "pip3"];
 const PIP_COMMANDS_WINDOWS: &[&str] = &["pip.exe", "pip3.exe"];
+const UV_COMMANDS: &[&str] = &["uv"];
+const UV_COMMANDS_WINDOWS: &[&str] = &["uv.exe"];
+const POETRY_COMMANDS: &[&str] = &["poetry"];
+const POETRY_COMMANDS_WINDOWS: &[&str] = &["poetry.exe", "poetry.bat"];
 const VALID_PYTHON_COMMANDS: &[&str] = &[
     "python",
     "python.exe",
@@ -26,8 +50,36 @@ const VALID_PYTHON_COMMANDS: &[&str] = &[
 ];
 const NPM_COMMANDS: &[&str] = &["npm"];
 const NPM_COMMANDS_WINDOWS: &[&str] = &["npm.cmd", "npm.ps1"];
-const NPX_COMMANDS: &[&str] = &["npx"];
-const NPX_COMMANDS_WINDOWS: &[&str] = &["npx.cmd", "npx.ps1"];
+const NPX_COMMANDS: &[&[&str]] = &[&["npx"]];
+const NPX_COMMANDS_WINDOWS: &[&[&str]] = &[&["npx.cmd"], &["npx.ps1"]];
+const PNPM_COMMANDS: &[&str] = &["pnpm"];
+const PNPM_COMMANDS_WINDOWS: &[&str] = &["pnpm.cmd", "pnpm.ps1"];
+const PNPM_EXEC_COMMANDS: &[&[&str]] = &[&["pnpm", "exec"]];
+const PNPM_EXEC_COMMANDS_WINDOWS: &[&[&str]] = &[&["pnpm.cmd", "exec"], &["pnpm.ps1", "exec"]];
+const YARN_COMMANDS: &[&str] = &["yarn"];
+const YARN_COMMANDS_WINDOWS: &[&str] = &["yarn.cmd", "yarn.ps1"];
+const YARN_RUNNER_COMMANDS: &[&[&str]] = &[&["yarn"]];
+const YARN_RUNNER_COMMANDS_WINDOWS: &[&[&str]] = &[&["yarn.cmd"], &["yarn.ps1"]];
+const BUN_COMMANDS: &[&str] = &["bun"];
+const BUN_COMMANDS_WINDOWS: &[&str] = &["bun.exe"];
+const BUNX_COMMANDS: &[&[&str]] = &[&["bunx"]];
+const BUNX_COMMANDS_WINDOWS: &[&[&str]] = &[&["bunx.exe"]];
+const GRADLE_COMMANDS: &[&str] = &["./gradlew", "gradle"];
+const GRADLE_COMMANDS_WINDOWS: &[&str] = &["gradlew.bat", "gradle.bat"];
+const MAVEN_COMMANDS: &[&str] = &["./mvnw", "mvn"];
+const MAVEN_COMMANDS_WINDOWS: &[&str] = &["mvnw.cmd", "mvn.cmd"];
+const JAVA_SRC_DIR_NAMES: &[&str] = &["src", "main", "java"];
+const JAVA_PART_DIR_NAMES: &[&str] = &["common", "domain", "features"];
+const DOTNET_COMMANDS: &[&str] = &["dotnet"];
+const DOTNET_COMMANDS_WINDOWS: &[&str] = &["dotnet.exe"];
+const PHP_COMMANDS: &[&str] = &["php"];
+const PHP_COMMANDS_WINDOWS: &[&str] = &["php.exe"];
+const COMPOSER_COMMANDS: &[&str] = &["composer"];
+const COMPOSER_COMMANDS_WINDOWS: &[&str] = &["composer.bat"];
+const PROJECT_WRAPPER_COMMANDS: &[&str] = &["./gradlew", "gradlew.bat", "./mvnw", "mvnw.cmd"];
+const CSPROJ_ROOT_NAMESPACE_OPEN: &str = "<RootNamespace>";
+const CSPROJ_ROOT_NAMESPACE_CLOSE: &str = "</RootNamespace>";
+const ANSWERS_FILE_JSON_EXTENSION: &str = "json";
 
 #[derive(Debug, Default, Clone, PartialEq)]
 pub enum Kind {
@@ -37,18 +89,582 @@ pub enum Kind {
     NodeJS,
     Rust,
     SvelteKit,
+    Java,
+    CSharp,
+    Php,
+    #[default]
+    Unknown,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum HttpFramework {
+    Fiber,
+    Gin,
+    Echo,
+    Chi,
+    Flask,
+    FastAPI,
+    ActixWeb,
+    Axum,
+    HyperExpress,
+    Express,
+    Fastify,
+    #[default]
+    Unknown,
+}
+
+impl HttpFramework {
+    pub const ALL: &'static [HttpFramework] = &[
+        HttpFramework::Fiber,
+        HttpFramework::Gin,
+        HttpFramework::Echo,
+        HttpFramework::Chi,
+        HttpFramework::Flask,
+        HttpFramework::FastAPI,
+        HttpFramework::ActixWeb,
+        HttpFramework::Axum,
+        HttpFramework::HyperExpress,
+        HttpFramework::Express,
+        HttpFramework::Fastify,
+    ];
+
+    #[inline]
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.trim().to_lowercase();
+
+        Self::ALL.iter().copied().find(|f| f.name() == name)
+    }
+
+    #[inline]
+    pub const fn is_valid(self) -> bool {
+        !matches!(self, HttpFramework::Unknown)
+    }
+
+    #[inline]
+    pub const fn name(self) -> &'static str {
+        match self {
+            HttpFramework::Fiber => "fiber",
+            HttpFramework::Gin => "gin",
+            HttpFramework::Echo => "echo",
+            HttpFramework::Chi => "chi",
+            HttpFramework::Flask => "flask",
+            HttpFramework::FastAPI => "fastapi",
+            HttpFramework::ActixWeb => "actix-web",
+            HttpFramework::Axum => "axum",
+            HttpFramework::HyperExpress => "hyper-express",
+            HttpFramework::Express => "express",
+            HttpFramework::Fastify => "fastify",
+            HttpFramework::Unknown => "unknown",
+        }
+    }
+
+    #[inline]
+    pub const fn title(self) -> &'static str {
+        match self {
+            HttpFramework::Fiber => "Fiber",
+            HttpFramework::Gin => "Gin",
+            HttpFramework::Echo => "Echo",
+            HttpFramework::Chi => "chi",
+            HttpFramework::Flask => "Flask",
+            HttpFramework::FastAPI => "FastAPI",
+            HttpFramework::ActixWeb => "Actix-Web",
+            HttpFramework::Axum => "axum",
+            HttpFramework::HyperExpress => "HyperExpress",
+            HttpFramework::Express => "Express",
+            HttpFramework::Fastify => "Fastify",
+            HttpFramework::Unknown => "Unknown",
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Database {
+    Postgres,
+    MySQL,
+    SQLite,
+    MongoDB,
+    Memory,
     #[default]
     Unknown,
 }
 
+impl Database {
+    pub const ALL: &'static [Database] = &[
+        Database::Postgres,
+        Database::MySQL,
+        Database::SQLite,
+        Database::MongoDB,
+        Database::Memory,
+    ];
+
+    #[inline]
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.trim().to_lowercase();
+        if name == "in-memory" {
+            return Some(Database::Memory);
+        }
+
+        Self::ALL.iter().copied().find(|d| d.name() == name)
+    }
+
+    #[inline]
+    pub const fn is_valid(self) -> bool {
+        !matches!(self, Database::Unknown)
+    }
+
+    #[inline]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Database::Postgres => "postgres",
+            Database::MySQL => "mysql",
+            Database::SQLite => "sqlite",
+            Database::MongoDB => "mongodb",
+            Database::Memory => "memory",
+            Database::Unknown => "unknown",
+        }
+    }
+
+    #[inline]
+    pub const fn title(self) -> &'static str {
+        match self {
+            Database::Postgres => "Postgres",
+            Database::MySQL => "MySQL",
+            Database::SQLite => "SQLite",
+            Database::MongoDB => "MongoDB",
+            Database::Memory => "in-memory",
+            Database::Unknown => "Unknown",
+        }
+    }
+
+    #[inline]
+    pub const fn pascal_case_name(self) -> &'static str {
+        match self {
+            Database::Memory => "Memory",
+            _ => self.title(),
+        }
+    }
+
+    pub fn env_vars(self, project_name: &str) -> Vec<(&'static str, String)> {
+        let server = |port: &str, user: Option<&str>| {
+            let mut vars = vec![
+                ("DB_HOST", "localhost".to_string()),
+                ("DB_PORT", port.to_string()),
+            ];
+            if let Some(user) = user {
+                vars.push(("DB_USER", user.to_string()));
+                vars.push(("DB_PASSWORD", user.to_string()));
+            }
+            vars.push(("DB_NAME", project_name.to_string()));
+            vars
+        };
+
+        match self {
+            Database::Postgres => server("5432", Some("postgres")),
+            Database::MySQL => server("3306", Some("root")),
+            Database::MongoDB => server("27017", None),
+            Database::SQLite => vec![("DB_PATH", format!("{}.sqlite3", project_name))],
+            Database::Memory | Database::Unknown => vec![],
+        }
+    }
+
+    pub fn compose_service(self, project_name: &str) -> String {
+        let (image, port, environment) = match self {
+            Database::Postgres => (
+                "postgres:16-alpine",
+                "5432",
+                vec![
+                    ("POSTGRES_USER", "postgres"),
+                    ("POSTGRES_PASSWORD", "postgres"),
+                    ("POSTGRES_DB", project_name),
+                ],
+            ),
+            Database::MySQL => (
+                "mysql:8",
+                "3306",
+                vec![("MYSQL_ROOT_PASSWORD", "root"), ("MYSQL_DATABASE", project_name)],
+            ),
+            Database::MongoDB => ("mongo:7", "27017", vec![]),
+            _ => return String::new(),
+        };
+
+        let service = format!("{}-{}", project_name, self.name());
+        let mut content = format!(
+            "    environment:\n      DB_HOST: {}\n    depends_on:\n      - {}\n",
+            service, service
+        );
+        content.push_str(&format!("  {}:\n    image: {}\n", service, image));
+        if !environment.is_empty() {
+            content.push_str("    environment:\n");
+            for (key, value) in environment {
+                content.push_str(&format!("      {}: {}\n", key, value));
+            }
+        }
+        content.push_str(&format!(
+            "    ports:\n      - \"{}:{}\"\n    restart: always\n",
+            port, port
+        ));
+
+        content
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum NodePackageManager {
+    Npm,
+    Pnpm,
+    Yarn,
+    Bun,
+    #[default]
+    Unknown,
+}
+
+impl NodePackageManager {
+    pub const ALL: &'static [NodePackageManager] = &[
+        NodePackageManager::Npm,
+        NodePackageManager::Pnpm,
+        NodePackageManager::Yarn,
+        NodePackageManager::Bun,
+    ];
+
+    #[inline]
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.trim().to_lowercase();
+
+        Self::ALL.iter().copied().find(|pm| pm.name() == name)
+    }
+
+    #[inline]
+    pub const fn is_valid(self) -> bool {
+        !matches!(self, NodePackageManager::Unknown)
+    }
+
+    #[inline]
+    pub const fn name(self) -> &'static str {
+        match self {
+            NodePackageManager::Npm => "npm",
+            NodePackageManager::Pnpm => "pnpm",
+            NodePackageManager::Yarn => "yarn",
+            NodePackageManager::Bun => "bun",
+            NodePackageManager::Unknown => "unknown",
+        }
+    }
+
+    #[inline]
+    pub const fn lockfiles(self) -> &'static [&'static str] {
+        match self {
+            NodePackageManager::Npm => &["package-lock.json"],
+            NodePackageManager::Pnpm => &["pnpm-lock.yaml"],
+            NodePackageManager::Yarn => &["yarn.lock"],
+            NodePackageManager::Bun => &["bun.lockb", "bun.lock"],
+            NodePackageManager::Unknown => &[],
+        }
+    }
+
+    #[inline]
+    const fn commands(self) -> (&'static [&'static str], &'static [&'static str]) {
+        match self {
+            NodePackageManager::Pnpm => (PNPM_COMMANDS, PNPM_COMMANDS_WINDOWS),
+            NodePackageManager::Yarn => (YARN_COMMANDS, YARN_COMMANDS_WINDOWS),
+            NodePackageManager::Bun => (BUN_COMMANDS, BUN_COMMANDS_WINDOWS),
+            _ => (NPM_COMMANDS, NPM_COMMANDS_WINDOWS),
+        }
+    }
+
+    #[inline]
+    const fn runner_commands(
+        self,
+    ) -> (&'static [&'static [&'static str]], &'static [&'static [&'static str]]) {
+        match self {
+            NodePackageManager::Pnpm => (PNPM_EXEC_COMMANDS, PNPM_EXEC_COMMANDS_WINDOWS),
+            NodePackageManager::Yarn => (YARN_RUNNER_COMMANDS, YARN_RUNNER_COMMANDS_WINDOWS),
+            NodePackageManager::Bun => (BUNX_COMMANDS, BUNX_COMMANDS_WINDOWS),
+            _ => (NPX_COMMANDS, NPX_COMMANDS_WINDOWS),
+        }
+    }
+
+    #[inline]
+    const fn add_subcommand(self) -> &'static str {
+        match self {
+            NodePackageManager::Pnpm | NodePackageManager::Yarn | NodePackageManager::Bun => "add",
+            _ => "install",
+        }
+    }
+
+    pub fn docker_setup(self) -> &'static str {
+        match self {
+            NodePackageManager::Pnpm => "RUN corepack enable\n",
+            NodePackageManager::Bun => "RUN npm install --global bun\n",
+            _ => "",
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum PythonToolchain {
+    Pip,
+    Uv,
+    Poetry,
+    #[default]
+    Unknown,
+}
+
+impl PythonToolchain {
+    pub const ALL: &'static [PythonToolchain] = &[
+        PythonToolchain::Pip,
+        PythonToolchain::Uv,
+        PythonToolchain::Poetry,
+    ];
+
+    #[inline]
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.trim().to_lowercase();
+
+        Self::ALL.iter().copied().find(|t| t.name() == name)
+    }
+
+    #[inline]
+    pub const fn is_valid(self) -> bool {
+        !matches!(self, PythonToolchain::Unknown)
+    }
+
+    #[inline]
+    pub const fn name(self) -> &'static str {
+        match self {
+            PythonToolchain::Pip => "pip",
+            PythonToolchain::Uv => "uv",
+            PythonToolchain::Poetry => "poetry",
+            PythonToolchain::Unknown => "unknown",
+        }
+    }
+
+    #[inline]
+    pub const fn venv_dir_name(self) -> &'static str {
+        match self {
+            PythonToolchain::Uv | PythonToolchain::Poetry => ".venv",
+            _ => "venv",
+        }
+    }
+
+    #[inline]
+    const fn lockfile(self) -> &'static str {
+        match self {
+            PythonToolchain::Uv => "uv.lock",
+            PythonToolchain::Poetry => "poetry.lock",
+            _ => "",
+        }
+    }
+
+    #[inline]
+    const fn commands(self) -> (&'static [&'static str], &'static [&'static str]) {
+        match self {
+            PythonToolchain::Uv => (UV_COMMANDS, UV_COMMANDS_WINDOWS),
+            PythonToolchain::Poetry => (POETRY_COMMANDS, POETRY_COMMANDS_WINDOWS),
+            _ => (PIP_COMMANDS, PIP_COMMANDS_WINDOWS),
+        }
+    }
+
+    pub fn docker_setup(self) -> &'static str {
+        match self {
+            PythonToolchain::Uv => "RUN pip install --no-cache-dir uv\n",
+            PythonToolchain::Poetry => "RUN pip install --no-cache-dir poetry\n",
+            _ => "",
+        }
+    }
+}
+
+trait Choice: Copy + Default + PartialEq {
+    fn from_name(name: &str) -> Option<Self>;
+    fn name(self) -> &'static str;
+}
+
+impl Choice for HttpFramework {
+    #[inline]
+    fn from_name(name: &str) -> Option<Self> {
+        HttpFramework::from_name(name)
+    }
+
+    #[inline]
+    fn name(self) -> &'static str {
+        HttpFramework::name(self)
+    }
+}
+
+impl Choice for Database {
+    #[inline]
+    fn from_name(name: &str) -> Option<Self> {
+        Database::from_name(name)
+    }
+
+    #[inline]
+    fn name(self) -> &'static str {
+        Database::name(self)
+    }
+}
+
+impl Choice for NodePackageManager {
+    #[inline]
+    fn from_name(name: &str) -> Option<Self> {
+        NodePackageManager::from_name(name)
+    }
+
+    #[inline]
+    fn name(self) -> &'static str {
+        NodePackageManager::name(self)
+    }
+}
+
+impl Choice for PythonToolchain {
+    #[inline]
+    fn from_name(name: &str) -> Option<Self> {
+        PythonToolchain::from_name(name)
+    }
+
+    #[inline]
+    fn name(self) -> &'static str {
+        PythonToolchain::name(self)
+    }
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct Prop {
     pub project_name: String,
     pub module_name: String,
     pub is_use_vendoring: bool,
+    pub http_framework: HttpFramework,
+    pub database: Database,
+    pub node_package_manager: NodePackageManager,
+    pub python_toolchain: PythonToolchain,
+    pub port: u16,
     project_dir: PathBuf,
 }
 
+impl Prop {
+    #[inline]
+    pub fn project_dir(&self) -> &Path {
+        &self.project_dir
+    }
+
+    #[inline]
+    pub fn set_project_dir<P: AsRef<Path>>(&mut self, dir: P) {
+        self.project_dir = dir.as_ref().to_path_buf();
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Preset {
+    pub project_name: Option<String>,
+    pub module_name: Option<String>,
+    pub is_use_vendoring: Option<bool>,
+    pub python_command: Option<String>,
+    pub python_toolchain: Option<String>,
+    pub http_framework: Option<String>,
+    pub database: Option<String>,
+    pub node_package_manager: Option<String>,
+    pub port: Option<String>,
+    pub is_git_init: Option<bool>,
+    pub git_message: Option<String>,
+    pub git_author: Option<String>,
+    pub answers_file: Option<PathBuf>,
+    pub project_dir: Option<PathBuf>,
+    pub is_assume_yes: bool,
+}
+
+impl Preset {
+    pub fn merge_answers_file(&mut self) -> Result<(), String> {
+        let Some(path) = self.answers_file.clone() else {
+            return Ok(());
+        };
+
+        let answers = load_answers_file(&path)?;
+        let Some(answers) = answers.as_object() else {
+            return Err(format!(
+                "The answers file `{}` must contain a table of answers",
+                path.display()
+            ));
+        };
+
+        for (key, value) in answers {
+            let invalid_value = || {
+                format!(
+                    "The answer `{}` in `{}` has an invalid value",
+                    key,
+                    path.display()
+                )
+            };
+
+            match key.as_str() {
+                "project_name" => {
+                    let value = value.as_str().ok_or_else(invalid_value)?;
+                    self.project_name.get_or_insert_with(|| value.to_string());
+                }
+                "module_name" => {
+                    let value = value.as_str().ok_or_else(invalid_value)?;
+                    self.module_name.get_or_insert_with(|| value.to_string());
+                }
+                "use_vendoring" => {
+                    let value = value.as_bool().ok_or_else(invalid_value)?;
+                    self.is_use_vendoring.get_or_insert(value);
+                }
+                "python_command" => {
+                    let value = value.as_str().ok_or_else(invalid_value)?;
+                    self.python_command.get_or_insert_with(|| value.to_string());
+                }
+                "python_toolchain" => {
+                    let value = value.as_str().ok_or_else(invalid_value)?;
+                    self.python_toolchain.get_or_insert_with(|| value.to_string());
+                }
+                "http_framework" => {
+                    let value = value.as_str().ok_or_else(invalid_value)?;
+                    self.http_framework.get_or_insert_with(|| value.to_string());
+                }
+                "database" => {
+                    let value = value.as_str().ok_or_else(invalid_value)?;
+                    self.database.get_or_insert_with(|| value.to_string());
+                }
+                "port" => {
+                    let value = value
+                        .as_u64()
+                        .map(|port| port.to_string())
+                        .or_else(|| value.as_str().map(String::from))
+                        .ok_or_else(invalid_value)?;
+                    self.port.get_or_insert(value);
+                }
+                "git_init" => {
+                    let value = value.as_bool().ok_or_else(invalid_value)?;
+                    self.is_git_init.get_or_insert(value);
+                }
+                "git_message" => {
+                    let value = value.as_str().ok_or_else(invalid_value)?;
+                    self.git_message.get_or_insert_with(|| value.to_string());
+                }
+                "git_author" => {
+                    let value = value.as_str().ok_or_else(invalid_value)?;
+                    self.git_author.get_or_insert_with(|| value.to_string());
+                }
+                "package_manager" => {
+                    let value = value.as_str().ok_or_else(invalid_value)?;
+                    self.node_package_manager.get_or_insert_with(|| value.to_string());
+                }
+                _ => {
+                    return Err(format!(
+                        "Unknown answer `{}` in `{}`",
+                        key,
+                        path.display()
+                    ))
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[inline]
+    pub fn is_git_init(&self) -> bool {
+        self.is_git_init
+            .unwrap_or(self.git_message.is_some() || self.git_author.is_some())
+    }
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct Command {
     pub base: String,
@@ -61,6 +677,7 @@ pub struct Command {
     base_custom: String,
     original_base: String,
     package_install: Vec<Vec<String>>,
+    is_offline: bool,
 }
 
 #[derive(Debug, Default, Clone)]
@@ -74,6 +691,7 @@ pub struct Lang {
     pub extensions: Vec<String>,
     pub command: Command,
     pub prop: Prop,
+    pub preset: Preset,
     os: OS,
 }
 
@@ -121,11 +739,22 @@ impl Lang {
                 Kind::SvelteKit,
                 "SvelteKit",
                 "sveltekit",
-                "src",
+                "src/lib",
                 vec![],
                 "",
                 vec![".ts", ".svelte"],
             ),
+            "java" => (
+                Kind::Java,
+                "Java",
+                "java",
+                "src/main/java",
+                vec![],
+                "",
+                vec![".java"],
+            ),
+            "csharp" | "cs" => (Kind::CSharp, "C#", "csharp", "", vec![], "", vec![".cs"]),
+            "php" => (Kind::Php, "PHP", "php", "src", vec![], "", vec![".php"]),
             _ => return Self::default(),
         };
 
@@ -159,6 +788,30 @@ impl Lang {
             return Self::new(os, "rust");
         }
 
+        if Self::is_java_project(dir) {
+            let mut lang = Self::new(os, "java");
+            lang.src_dir_name = java_src_dir_name(&find_java_package(dir));
+
+            return lang;
+        }
+
+        if Self::is_csharp_project(dir) {
+            return Self::new(os, "csharp");
+        }
+
+        if Self::is_php_project(dir) {
+            let mut lang = Self::new(os, "php");
+            if let Some((_, src_dir_name)) = find_php_psr4_root(dir) {
+                lang.src_dir_name = src_dir_name;
+            }
+
+            return lang;
+        }
+
+        if Self::is_sveltekit_project(dir) {
+            return Self::new(os, "sveltekit");
+        }
+
         if Self::is_typescript_project(dir) {
             return Self::new(os, "typescript");
         }
@@ -193,6 +846,28 @@ impl Lang {
             || dir.join("src/main.rs").exists()
     }
 
+    #[inline]
+    fn is_java_project(dir: &Path) -> bool {
+        is_dir_has(dir, &["build.gradle"], &[])
+            || is_dir_has(dir, &["build.gradle.kts"], &[])
+            || is_dir_has(dir, &["pom.xml"], &[])
+    }
+
+    #[inline]
+    fn is_csharp_project(dir: &Path) -> bool {
+        is_dir_has(dir, &[], &["csproj", "sln"])
+    }
+
+    #[inline]
+    fn is_php_project(dir: &Path) -> bool {
+        is_dir_has(dir, &["composer.json"], &[])
+    }
+
+    #[inline]
+    fn is_sveltekit_project(dir: &Path) -> bool {
+        is_dir_has(dir, &["svelte.config.js"], &[]) || is_dir_has(dir, &["svelte.config.ts"], &[])
+    }
+
     #[inline]
     fn is_typescript_project(dir: &Path) -> bool {
         is_dir_has(dir, &[], &["ts"])
@@ -216,6 +891,233 @@ impl Lang {
     pub fn get_main_file_extension(&self) -> String {
         self.extensions.first().cloned().unwrap_or_default()
     }
+
+    #[inline]
+    pub fn package_dir(&self) -> String {
+        match self.kind {
+            Kind::Java => self.prop.module_name.replace('.', MAIN_SEPARATOR_STR),
+            _ => String::new(),
+        }
+    }
+
+    #[inline]
+    pub fn http_frameworks(&self) -> &'static [HttpFramework] {
+        match self.kind {
+            Kind::Go => &[
+                HttpFramework::Fiber,
+                HttpFramework::Gin,
+                HttpFramework::Echo,
+                HttpFramework::Chi,
+            ],
+            Kind::Python => &[HttpFramework::Flask, HttpFramework::FastAPI],
+            Kind::TypeScript | Kind::NodeJS => &[
+                HttpFramework::HyperExpress,
+                HttpFramework::Express,
+                HttpFramework::Fastify,
+            ],
+            Kind::Rust => &[HttpFramework::ActixWeb, HttpFramework::Axum],
+            _ => &[],
+        }
+    }
+
+    #[inline]
+    pub fn http_framework(&self) -> HttpFramework {
+        if self.prop.http_framework.is_valid() {
+            return self.prop.http_framework;
+        }
+
+        self.http_frameworks()
+            .first()
+            .copied()
+            .unwrap_or_default()
+    }
+
+    #[inline]
+    fn find_http_framework(&self, name: &str) -> Option<HttpFramework> {
+        HttpFramework::from_name(name).filter(|f| self.http_frameworks().contains(f))
+    }
+
+    #[inline]
+    pub fn databases(&self) -> &'static [Database] {
+        match self.kind {
+            Kind::Go | Kind::Python | Kind::TypeScript | Kind::NodeJS | Kind::Rust => Database::ALL,
+            _ => &[],
+        }
+    }
+
+    #[inline]
+    pub fn database(&self) -> Database {
+        if self.prop.database.is_valid() {
+            return self.prop.database;
+        }
+
+        self.databases().first().copied().unwrap_or_default()
+    }
+
+    #[inline]
+    pub fn find_database(&self, name: &str) -> Option<Database> {
+        Database::from_name(name).filter(|d| self.databases().contains(d))
+    }
+
+    #[inline]
+    pub fn node_package_managers(&self) -> &'static [NodePackageManager] {
+        match self.kind {
+            Kind::TypeScript | Kind::NodeJS | Kind::SvelteKit => NodePackageManager::ALL,
+            _ => &[],
+        }
+    }
+
+    #[inline]
+    pub fn node_package_manager(&self) -> NodePackageManager {
+        if self.prop.node_package_manager.is_valid() {
+            return self.prop.node_package_manager;
+        }
+
+        self.node_package_managers()
+            .first()
+            .copied()
+            .unwrap_or_default()
+    }
+
+    #[inline]
+    fn find_node_package_manager(&self, name: &str) -> Option<NodePackageManager> {
+        NodePackageManager::from_name(name).filter(|pm| self.node_package_managers().contains(pm))
+    }
+
+    #[inline]
+    pub fn python_toolchains(&self) -> &'static [PythonToolchain] {
+        match self.kind {
+            Kind::Python => PythonToolchain::ALL,
+            _ => &[],
+        }
+    }
+
+    #[inline]
+    pub fn python_toolchain(&self) -> PythonToolchain {
+        if self.prop.python_toolchain.is_valid() {
+            return self.prop.python_toolchain;
+        }
+
+        self.python_toolchains().first().copied().unwrap_or_default()
+    }
+
+    #[inline]
+    fn find_python_toolchain(&self, name: &str) -> Option<PythonToolchain> {
+        PythonToolchain::from_name(name).filter(|t| self.python_toolchains().contains(t))
+    }
+
+    #[inline]
+    pub fn port(&self) -> u16 {
+        if self.prop.port > 0 {
+            return self.prop.port;
+        }
+
+        DEFAULT_PORT
+    }
+
+    #[inline]
+    pub fn routes_path(&self) -> PathBuf {
+        match self.kind {
+            Kind::Go => PathBuf::from("main.go"),
+            Kind::Python => PathBuf::from("main.py"),
+            Kind::TypeScript => PathBuf::from("main.ts"),
+            Kind::NodeJS => PathBuf::from("main.js"),
+            Kind::Rust => ["src", "main.rs"].iter().collect(),
+            Kind::SvelteKit => ["src", "routes"].iter().collect(),
+            Kind::Java => JAVA_SRC_DIR_NAMES
+                .iter()
+                .collect::<PathBuf>()
+                .join(self.package_dir())
+                .join("Main.java"),
+            Kind::CSharp => PathBuf::from("Program.cs"),
+            Kind::Php => PathBuf::from("index.php"),
+            Kind::Unknown => PathBuf::new(),
+        }
+    }
+
+    #[inline]
+    pub fn is_windows(&self) -> bool {
+        self.os.kind == OSKind::Windows
+    }
+
+    #[inline]
+    pub fn set_offline(&mut self, is_offline: bool) {
+        self.command.is_offline = is_offline;
+    }
+
+    pub fn for_os(&self, kind: OSKind) -> Self {
+        let mut lang = self.clone();
+        lang.os.kind = kind;
+        lang.compose_command();
+
+        lang
+    }
+
+    #[inline]
+    pub fn package_manager_setup(&self) -> &'static str {
+        match self.kind {
+            Kind::Python => self.python_toolchain().docker_setup(),
+            _ => self.node_package_manager().docker_setup(),
+        }
+    }
+
+    pub fn database_packages(&self, database: Database) -> Vec<&'static str> {
+        let packages: &[&str] = match (&self.kind, database) {
+            (Kind::Go, Database::Postgres) => &["github.com/jackc/pgx/v5"],
+            (Kind::Go, Database::MySQL) => &["github.com/go-sql-driver/mysql"],
+            (Kind::Go, Database::SQLite) => &["modernc.org/sqlite"],
+            (Kind::Go, Database::MongoDB) => &["go.mongodb.org/mongo-driver/v2"],
+            (Kind::Python, Database::Postgres) => &["psycopg[binary]"],
+            (Kind::Python, Database::MySQL) => &["PyMySQL"],
+            (Kind::Python, Database::MongoDB) => &["pymongo"],
+            (Kind::TypeScript, Database::Postgres) => &["pg", "@types/pg"],
+            (Kind::TypeScript, Database::SQLite) => &["better-sqlite3", "@types/better-sqlite3"],
+            (Kind::TypeScript | Kind::NodeJS, Database::MySQL) => &["mysql2"],
+            (Kind::TypeScript | Kind::NodeJS, Database::MongoDB) => &["mongodb"],
+            (Kind::NodeJS, Database::Postgres) => &["pg"],
+            (Kind::NodeJS, Database::SQLite) => &["better-sqlite3"],
+            _ => &[],
+        };
+
+        packages.to_vec()
+    }
+
+    pub fn database_manifest_entries(&self, database: Database) -> Vec<&'static str> {
+        let entries: &[&str] = match (&self.kind, database) {
+            (Kind::Rust, Database::Postgres) => {
+                &[r#"sqlx = { version = "0.8.6", features = ["runtime-tokio", "postgres"] }"#]
+            }
+            (Kind::Rust, Database::MySQL) => {
+                &[r#"sqlx = { version = "0.8.6", features = ["runtime-tokio", "mysql"] }"#]
+            }
+            (Kind::Rust, Database::SQLite) => {
+                &[r#"sqlx = { version = "0.8.6", features = ["runtime-tokio", "sqlite"] }"#]
+            }
+            (Kind::Rust, Database::MongoDB) => &[r#"mongodb = "3.2.4""#],
+            _ => &[],
+        };
+
+        entries.to_vec()
+    }
+
+    #[inline]
+    pub fn manifest_file_name(&self) -> Option<&'static str> {
+        match self.kind {
+            Kind::Rust => Some("Cargo.toml"),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn scaffold_files(&self) -> &'static [(&'static str, &'static str)] {
+        match self.kind {
+            Kind::Rust => &[
+                ("src/main.rs", RUST_SCAFFOLD_MAIN),
+                (".gitignore", RUST_SCAFFOLD_GITIGNORE),
+            ],
+            _ => &[],
+        }
+    }
 }
 
 impl Lang {
@@ -260,16 +1162,198 @@ impl Lang {
 }
 
 impl Lang {
+    pub fn validate_preset(&self) -> Result<(), String> {
+        if let Some(name) = &self.preset.project_name {
+            if name.is_empty() {
+                return Err("Project name cannot be empty".to_string());
+            }
+            if name.contains(' ') {
+                return Err("Project name cannot contain space".to_string());
+            }
+            if self.preset.project_dir.is_none() && Path::new(name).exists() {
+                return Err(format!("Project `{}` already exists", name));
+            }
+        }
+
+        if let Some(dir) = &self.preset.project_dir {
+            if dir.exists() && !dir.is_dir() {
+                return Err(format!("`{}` is not a directory", dir.display()));
+            }
+        }
+
+        if let Some(module) = &self.preset.module_name {
+            if module.is_empty() {
+                return Err("Module name cannot be empty".to_string());
+            }
+            if self.kind == Kind::Java && !is_qualified_name(module, '.') {
+                return Err(format!("`{}` is not a valid Java package name", module));
+            }
+            if self.kind == Kind::CSharp && !is_qualified_name(module, '.') {
+                return Err(format!("`{}` is not a valid C# namespace", module));
+            }
+            if self.kind == Kind::Php && !is_qualified_name(module, '\\') {
+                return Err(format!("`{}` is not a valid PHP namespace", module));
+            }
+        }
+
+        if let Some(name) = &self.preset.http_framework {
+            if self.find_http_framework(name).is_none() {
+                let names: Vec<&str> = self.http_frameworks().iter().map(|f| f.name()).collect();
+
+                return Err(if names.is_empty() {
+                    format!("{} does not support choosing an HTTP framework", self.name)
+                } else {
+                    format!(
+                        "`{}` is not a supported HTTP framework for {}. Available: {}",
+                        name,
+                        self.name,
+                        names.join(", ")
+                    )
+                });
+            }
+        }
+
+        if let Some(name) = &self.preset.database {
+            self.validate_database(name)?;
+        }
+
+        if let Some(port) = &self.preset.port {
+            if parse_port(port).is_none() {
+                return Err(format!("`{}` is not a valid port", port));
+            }
+        }
+
+        if let Some(message) = &self.preset.git_message {
+            if message.trim().is_empty() {
+                return Err("Git commit message cannot be empty".to_string());
+            }
+        }
+
+        if let Some(author) = &self.preset.git_author {
+            if parse_git_author(author).is_none() {
+                return Err(format!(
+                    "`{}` is not a valid Git author, use 'Name <email>'",
+                    author
+                ));
+            }
+        }
+
+        if let Some(name) = &self.preset.node_package_manager {
+            if self.find_node_package_manager(name).is_none() {
+                let names: Vec<&str> = self
+                    .node_package_managers()
+                    .iter()
+                    .map(|pm| pm.name())
+                    .collect();
+
+                return Err(if names.is_empty() {
+                    format!("{} does not support choosing a package manager", self.name)
+                } else {
+                    format!(
+                        "`{}` is not a supported package manager for {}. Available: {}",
+                        name,
+                        self.name,
+                        names.join(", ")
+                    )
+                });
+            }
+        }
+
+        if let Some(name) = &self.preset.python_toolchain {
+            let toolchain = self.find_python_toolchain(name);
+            if toolchain == Some(PythonToolchain::Uv) && self.preset.is_use_vendoring == Some(false)
+            {
+                return Err(
+                    "uv always uses a virtual environment and cannot be combined with --no-vendor"
+                        .to_string(),
+                );
+            }
+            if toolchain.is_none() {
+                let names: Vec<&str> = self.python_toolchains().iter().map(|t| t.name()).collect();
+
+                return Err(if names.is_empty() {
+                    format!("{} does not support choosing a Python toolchain", self.name)
+                } else {
+                    format!(
+                        "`{}` is not a supported Python toolchain. Available: {}",
+                        name,
+                        names.join(", ")
+                    )
+                });
+            }
+        }
+
+        if let Some(cmd) = &self.preset.python_command {
+            if !VALID_PYTHON_COMMANDS.contains(&cmd.as_str()) {
+                return Err(format!("`{}` is not a recognized Python command", cmd));
+            }
+            if !check_command(&self.prop.project_dir, cmd) {
+                return Err(format!("`{}` command does not exist on this system", cmd));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn validate_database(&self, name: &str) -> Result<(), String> {
+        if self.find_database(name).is_some() {
+            return Ok(());
+        }
+
+        let names: Vec<&str> = self.databases().iter().map(|d| d.name()).collect();
+
+        Err(if names.is_empty() {
+            format!(
+                "{} does not support choosing a database, its repositories are in-memory only",
+                self.name
+            )
+        } else {
+            format!(
+                "`{}` is not a supported database for {}. Available: {}",
+                name,
+                self.name,
+                names.join(", ")
+            )
+        })
+    }
+
     pub fn compose_prop_from_input(&mut self) {
-        self.prop.project_name = self.read_project_name();
-        self.prop.project_dir = PathBuf::from(&self.prop.project_name);
+        self.prop.project_name = match (&self.preset.project_name, &self.preset.project_dir) {
+            (Some(name), _) => name.clone(),
+            (None, Some(dir)) => dirname(dir.canonicalize().unwrap_or_else(|_| cwd().join(dir))),
+            (None, None) => self.read_project_name(),
+        };
+        self.prop.project_dir = match &self.preset.project_dir {
+            Some(dir) => dir.clone(),
+            None => PathBuf::from(&self.prop.project_name),
+        };
 
         match self.kind {
             Kind::Go => self.compose_go_props(),
             Kind::Python => self.compose_python_props(),
+            Kind::Java => self.compose_java_props(),
+            Kind::CSharp => self.compose_namespace_props('.', "MyCompany.Project"),
+            Kind::Php => self.compose_namespace_props('\\', "MyCompany\\Project"),
             _ => {}
         }
 
+        self.prop.http_framework = match &self.preset.http_framework {
+            Some(name) => self.find_http_framework(name).unwrap_or_default(),
+            None => self.read_choice("HTTP framework", self.http_frameworks()),
+        };
+        self.prop.database = match &self.preset.database {
+            Some(name) => self.find_database(name).unwrap_or_default(),
+            None => self.read_choice("database", self.databases()),
+        };
+        self.prop.node_package_manager = match &self.preset.node_package_manager {
+            Some(name) => self.find_node_package_manager(name).unwrap_or_default(),
+            None => self.read_choice("package manager", self.node_package_managers()),
+        };
+        self.prop.port = match &self.preset.port {
+            Some(port) => parse_port(port).unwrap_or(DEFAULT_PORT),
+            None => self.read_port(),
+        };
+
         self.compose_command();
     }
 
@@ -291,42 +1375,238 @@ impl Lang {
                 continue;
             }
 
-            return name;
+            return name;
+        }
+    }
+
+    fn read_choice<T: Choice>(&self, label: &str, options: &[T]) -> T {
+        let Some(default) = options.first().copied() else {
+            return T::default();
+        };
+        if self.preset.is_assume_yes {
+            return default;
+        }
+
+        let names: Vec<&str> = options.iter().map(|o| o.name()).collect();
+        let mut prompt = format!(
+            "Choose {} ({}), press {} to use '{}': ",
+            label,
+            names.join(", "),
+            "Enter".bright_white().bold(),
+            default.name().bright_white()
+        );
+
+        loop {
+            let name = read_input(&prompt);
+            if name.trim().is_empty() {
+                return default;
+            }
+
+            if let Some(choice) = T::from_name(&name).filter(|c| options.contains(c)) {
+                return choice;
+            }
+
+            prompt = format!(
+                "`{}` is not available. Choose one of ({}): ",
+                name.trim().bright_red(),
+                names.join(", ")
+            );
+        }
+    }
+
+    fn read_port(&self) -> u16 {
+        if self.preset.is_assume_yes {
+            return DEFAULT_PORT;
+        }
+
+        let mut prompt = format!(
+            "Enter service port, press {} to use '{}': ",
+            "Enter".bright_white().bold(),
+            DEFAULT_PORT.to_string().bright_white()
+        );
+
+        loop {
+            let port = read_input(&prompt);
+            if port.trim().is_empty() {
+                return DEFAULT_PORT;
+            }
+
+            if let Some(port) = parse_port(&port) {
+                return port;
+            }
+
+            prompt = format!(
+                "`{}` is not a valid port. Enter a number between 1 and {}: ",
+                port.trim().bright_red(),
+                u16::MAX
+            );
+        }
+    }
+
+    fn compose_go_props(&mut self) {
+        self.prop.module_name = match self.preset.module_name.clone() {
+            Some(module) => module,
+            None => self.read_go_module_name(),
+        };
+
+        self.prop.is_use_vendoring = match self.preset.is_use_vendoring {
+            Some(is_use_vendoring) => is_use_vendoring,
+            None => !self.ask_to_disable_feature("vendoring", "We use vendoring by default"),
+        };
+    }
+
+    fn read_go_module_name(&self) -> String {
+        let mut prompt = "Enter Go module name (ex: github.com/user/project): ";
+
+        loop {
+            let module = read_input(prompt);
+            if module.is_empty() {
+                prompt = "Go module cannot be empty. Enter another name: ";
+                continue;
+            }
+
+            return module;
+        }
+    }
+
+    fn compose_java_props(&mut self) {
+        self.prop.module_name = match self.preset.module_name.clone() {
+            Some(package) => package,
+            None => self.read_java_package_name(),
+        };
+        self.src_dir_name = java_src_dir_name(&self.prop.module_name);
+    }
+
+    fn read_java_package_name(&self) -> String {
+        let default: String = self
+            .prop
+            .project_name
+            .to_lowercase()
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric())
+            .collect();
+        let default = if is_qualified_name(&default, '.') {
+            format!("com.example.{}", default)
+        } else {
+            "com.example.app".to_string()
+        };
+        if self.preset.is_assume_yes {
+            return default;
+        }
+        let mut prompt = format!(
+            "Enter Java base package (ex: com.example.project), press {} to use '{}': ",
+            "Enter".bright_white().bold(),
+            default.bright_white()
+        );
+
+        loop {
+            let package = read_input(&prompt);
+            if package.trim().is_empty() {
+                return default;
+            }
+            if !is_qualified_name(package.trim(), '.') {
+                prompt = format!(
+                    "`{}` is not a valid Java package name. Enter another name: ",
+                    package.trim().bright_red()
+                );
+                continue;
+            }
+
+            return package.trim().to_string();
         }
     }
 
-    fn compose_go_props(&mut self) {
-        let mut prompt = "Enter Go module name (ex: github.com/user/project): ";
+    fn compose_namespace_props(&mut self, separator: char, example: &str) {
+        self.prop.module_name = match self.preset.module_name.clone() {
+            Some(namespace) => namespace,
+            None => self.read_root_namespace(separator, example),
+        };
+    }
+
+    fn read_root_namespace(&self, separator: char, example: &str) -> String {
+        let default: String = to_pascal_case(&self.prop.project_name)
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric())
+            .collect();
+        let default = if is_qualified_name(&default, separator) {
+            default
+        } else {
+            "App".to_string()
+        };
+        if self.preset.is_assume_yes {
+            return default;
+        }
+        let mut prompt = format!(
+            "Enter {} root namespace (ex: {}), press {} to use '{}': ",
+            self.name,
+            example,
+            "Enter".bright_white().bold(),
+            default.bright_white()
+        );
 
         loop {
-            let module = read_input(prompt);
-            if module.is_empty() {
-                prompt = "Go module cannot be empty. Enter another name: ";
+            let namespace = read_input(&prompt);
+            if namespace.trim().is_empty() {
+                return default;
+            }
+            if !is_qualified_name(namespace.trim(), separator) {
+                prompt = format!(
+                    "`{}` is not a valid {} namespace. Enter another name: ",
+                    namespace.trim().bright_red(),
+                    self.name
+                );
                 continue;
             }
 
-            self.prop.module_name = module;
-            break;
+            return namespace.trim().to_string();
         }
-
-        self.prop.is_use_vendoring =
-            !self.ask_to_disable_feature("vendoring", "We use vendoring by default");
     }
 
     fn compose_python_props(&mut self) {
         self.determine_base_command();
 
-        self.prop.is_use_vendoring = !self.ask_to_disable_feature(
-            "virtual environment (venv)",
-            "We use virtual environment (venv) by default",
-        );
+        self.prop.python_toolchain = match &self.preset.python_toolchain {
+            Some(name) => self.find_python_toolchain(name).unwrap_or_default(),
+            None => {
+                // uv cannot run without its virtual environment, so it is not offered when the
+                // venv is disabled.
+                let toolchains: Vec<PythonToolchain> = self
+                    .python_toolchains()
+                    .iter()
+                    .copied()
+                    .filter(|t| {
+                        self.preset.is_use_vendoring != Some(false) || *t != PythonToolchain::Uv
+                    })
+                    .collect();
+
+                self.read_choice("Python toolchain", &toolchains)
+            }
+        };
+
+        self.prop.is_use_vendoring = match self.preset.is_use_vendoring {
+            _ if self.prop.python_toolchain == PythonToolchain::Uv => true,
+            Some(is_use_vendoring) => is_use_vendoring,
+            None => !self.ask_to_disable_feature(
+                "virtual environment (venv)",
+                "We use virtual environment (venv) by default",
+            ),
+        };
 
-        if self.confirm_custom_python_command() {
+        if let Some(cmd) = self.preset.python_command.clone() {
+            self.command.base = cmd.clone();
+            self.command.base_custom = cmd;
+        } else if self.prop.python_toolchain == PythonToolchain::Pip
+            && self.confirm_custom_python_command()
+        {
             self.read_custom_python_command();
         }
     }
 
     fn ask_to_disable_feature(&self, _feature_name: &str, message: &str) -> bool {
+        if self.preset.is_assume_yes {
+            return false;
+        }
+
         let response = read_input(&format!(
             "{}, Type '{}' and press {} if you don't want to use it: ",
             message,
@@ -338,6 +1618,10 @@ impl Lang {
     }
 
     fn confirm_custom_python_command(&self) -> bool {
+        if self.preset.is_assume_yes {
+            return false;
+        }
+
         let response = read_input(&format!(
             "\nBy default, we use the '{}' command to run post-installation setup. However, on some systems, this may point to an irrelevant Python version. If you want to use a different command (e.g., 'python3'), type '{}' and press {}. Otherwise, the default will be used: ",
             self.command.base.bright_white(),
@@ -398,6 +1682,9 @@ impl Lang {
                 self.extract_package_json_props(dir)
             }
             Kind::Rust => self.extract_rust_props(dir),
+            Kind::Java => self.extract_java_props(dir),
+            Kind::CSharp => self.extract_csharp_props(dir),
+            Kind::Php => self.extract_php_props(dir),
             _ => {}
         }
 
@@ -411,7 +1698,6 @@ impl Lang {
                 .find(|l| l.starts_with("module "))
                 .and_then(|line| {
                     line.trim_start_matches("module ")
-                        .trim()
                         .split_whitespace()
                         .next()
                 })
@@ -442,7 +1728,11 @@ impl Lang {
                 .to_string();
         }
 
-        self.prop.is_use_vendoring = cwd().join("venv").is_dir();
+        self.prop.python_toolchain = [PythonToolchain::Uv, PythonToolchain::Poetry]
+            .into_iter()
+            .find(|t| dir.join(t.lockfile()).is_file())
+            .unwrap_or(PythonToolchain::Pip);
+        self.prop.is_use_vendoring = dir.join(self.prop.python_toolchain.venv_dir_name()).is_dir();
     }
 
     fn extract_package_json_props(&mut self, dir: &Path) {
@@ -455,6 +1745,12 @@ impl Lang {
                     .to_string();
             }
         }
+
+        self.prop.node_package_manager = NodePackageManager::ALL
+            .iter()
+            .copied()
+            .find(|pm| pm.lockfiles().iter().any(|f| dir.join(f).is_file()))
+            .unwrap_or(NodePackageManager::Npm);
     }
 
     fn extract_rust_props(&mut self, dir: &Path) {
@@ -470,6 +1766,36 @@ impl Lang {
         }
     }
 
+    fn extract_java_props(&mut self, dir: &Path) {
+        self.prop.module_name = find_java_package(dir);
+        self.src_dir_name = java_src_dir_name(&self.prop.module_name);
+    }
+
+    fn extract_csharp_props(&mut self, dir: &Path) {
+        let Some(csproj) = find_csproj_file(dir) else {
+            return;
+        };
+
+        self.prop.module_name = read_file(&csproj)
+            .ok()
+            .and_then(|content| {
+                let start = content.find(CSPROJ_ROOT_NAMESPACE_OPEN)?;
+                let rest = &content[start + CSPROJ_ROOT_NAMESPACE_OPEN.len()..];
+                let end = rest.find(CSPROJ_ROOT_NAMESPACE_CLOSE)?;
+
+                Some(rest[..end].trim().to_string())
+            })
+            .filter(|namespace| !namespace.is_empty())
+            .unwrap_or_else(|| file_stem(&csproj));
+    }
+
+    fn extract_php_props(&mut self, dir: &Path) {
+        if let Some((namespace, src_dir_name)) = find_php_psr4_root(dir) {
+            self.prop.module_name = namespace;
+            self.src_dir_name = src_dir_name;
+        }
+    }
+
     pub fn compose_command(&mut self) {
         self.determine_original_base_command();
         self.determine_base_command();
@@ -498,6 +1824,9 @@ impl Lang {
             }
             Kind::NodeJS | Kind::TypeScript | Kind::SvelteKit => &["node"],
             Kind::Rust => &["cargo"],
+            Kind::Java => &["java"],
+            Kind::CSharp => &["dotnet"],
+            Kind::Php => &["php"],
             _ => &[],
         };
 
@@ -532,6 +1861,11 @@ impl Lang {
             }
             Kind::NodeJS | Kind::TypeScript | Kind::SvelteKit => vec!["node".to_string()],
             Kind::Rust => vec!["cargo".to_string()],
+            Kind::Java => vec!["java".to_string()],
+            Kind::CSharp => {
+                self.build_common_command_candidates(DOTNET_COMMANDS, DOTNET_COMMANDS_WINDOWS)
+            }
+            Kind::Php => self.build_common_command_candidates(PHP_COMMANDS, PHP_COMMANDS_WINDOWS),
             _ => vec![],
         }
     }
@@ -539,19 +1873,50 @@ impl Lang {
     fn determine_package_manager_command(&mut self) {
         let candidates = match self.kind {
             Kind::Go => vec!["go".to_string()],
-            Kind::Python => {
+            Kind::Python if self.python_toolchain() == PythonToolchain::Pip => {
                 self.build_python_command_candidates(PIP_COMMANDS, PIP_COMMANDS_WINDOWS)
             }
+            Kind::Python => {
+                let (commands, windows_commands) = self.python_toolchain().commands();
+                self.build_common_command_candidates(commands, windows_commands)
+            }
             Kind::NodeJS | Kind::TypeScript | Kind::SvelteKit => {
-                self.build_common_command_candidates(NPM_COMMANDS, NPM_COMMANDS_WINDOWS)
+                let (commands, windows_commands) = self.node_package_manager().commands();
+                self.build_common_command_candidates(commands, windows_commands)
             }
             Kind::Rust => vec!["cargo".to_string()],
+            Kind::Java if self.is_maven_project() => {
+                self.build_common_command_candidates(MAVEN_COMMANDS, MAVEN_COMMANDS_WINDOWS)
+            }
+            Kind::Java => {
+                self.build_common_command_candidates(GRADLE_COMMANDS, GRADLE_COMMANDS_WINDOWS)
+            }
+            Kind::CSharp => {
+                self.build_common_command_candidates(DOTNET_COMMANDS, DOTNET_COMMANDS_WINDOWS)
+            }
+            Kind::Php => {
+                self.build_common_command_candidates(COMPOSER_COMMANDS, COMPOSER_COMMANDS_WINDOWS)
+            }
             _ => vec![],
         };
 
         self.command.package_manager = self
             .find_first_available_command_owned(&candidates)
             .unwrap_or_default();
+        if self.command.package_manager.is_empty() {
+            self.command.package_manager = match self.kind {
+                Kind::Python if self.python_toolchain() != PythonToolchain::Pip => {
+                    self.python_toolchain().name().to_string()
+                }
+                Kind::Python if self.prop.is_use_vendoring => {
+                    candidates.first().cloned().unwrap_or_default()
+                }
+                _ if self.node_package_manager().is_valid() => {
+                    self.node_package_manager().name().to_string()
+                }
+                _ => String::new(),
+            };
+        }
     }
 
     fn build_common_command_candidates(
@@ -600,13 +1965,13 @@ impl Lang {
 
     #[inline]
     fn build_venv_command(&self, cmd: &str) -> String {
-        let prefix = if self.os.kind == OSKind::Windows {
-            ["venv", "Scripts", cmd]
-        } else {
-            ["venv", "bin", cmd]
-        };
+        let venv_dir_name = self.python_toolchain().venv_dir_name();
 
-        join_text(&prefix, MAIN_SEPARATOR_STR)
+        if self.os.kind == OSKind::Windows {
+            join_text(&[venv_dir_name, "Scripts", cmd], "\\")
+        } else {
+            join_text(&[venv_dir_name, "bin", cmd], "/")
+        }
     }
 
     fn determine_module_init_command(&mut self) {
@@ -619,10 +1984,19 @@ impl Lang {
                     MODULE_NAME_PLACEHOLDER.to_string(),
                 ]]
             }
-            Kind::Rust if !self.command.package_manager.is_empty() => {
+            Kind::Java
+                if !self.command.package_manager.is_empty() && !self.is_maven_project() =>
+            {
                 vec![vec![
                     self.command.package_manager.clone(),
-                    "init".to_string(),
+                    "wrapper".to_string(),
+                ]]
+            }
+            Kind::CSharp if !self.command.package_manager.is_empty() => {
+                vec![vec![
+                    self.command.package_manager.clone(),
+                    "new".to_string(),
+                    "editorconfig".to_string(),
                 ]]
             }
             _ => vec![],
@@ -636,6 +2010,14 @@ impl Lang {
                 "mod".to_string(),
                 "tidy".to_string(),
             ]],
+            Kind::Python if self.python_toolchain() == PythonToolchain::Uv => vec![vec![
+                self.command.package_manager.clone(),
+                "sync".to_string(),
+            ]],
+            Kind::Python if self.python_toolchain() == PythonToolchain::Poetry => vec![vec![
+                self.command.package_manager.clone(),
+                "install".to_string(),
+            ]],
             Kind::Python => vec![
                 vec![
                     self.command.package_manager.clone(),
@@ -646,7 +2028,7 @@ impl Lang {
                 vec![
                     self.command.package_manager.clone(),
                     "freeze".to_string(),
-                    ">".to_string(),
+                    STDOUT_REDIRECT.to_string(),
                     "requirements.txt".to_string(),
                 ],
             ],
@@ -656,6 +2038,26 @@ impl Lang {
                     "install".to_string(),
                 ]]
             }
+            Kind::Rust => vec![vec![
+                self.command.package_manager.clone(),
+                "fetch".to_string(),
+            ]],
+            Kind::Java if self.is_maven_project() => vec![vec![
+                self.command.package_manager.clone(),
+                "dependency:resolve".to_string(),
+            ]],
+            Kind::Java => vec![vec![
+                self.command.package_manager.clone(),
+                "dependencies".to_string(),
+            ]],
+            Kind::CSharp => vec![vec![
+                self.command.package_manager.clone(),
+                "restore".to_string(),
+            ]],
+            Kind::Php if !self.command.package_manager.is_empty() => vec![vec![
+                self.command.package_manager.clone(),
+                "install".to_string(),
+            ]],
             _ => vec![],
         };
     }
@@ -667,6 +2069,11 @@ impl Lang {
                 "get".to_string(),
                 PACKAGE_NAME_PLACEHOLDER.to_string(),
             ]],
+            Kind::Python if self.python_toolchain() != PythonToolchain::Pip => vec![vec![
+                self.command.package_manager.clone(),
+                "add".to_string(),
+                PACKAGE_NAME_PLACEHOLDER.to_string(),
+            ]],
             Kind::Python => vec![
                 vec![
                     self.command.package_manager.clone(),
@@ -676,14 +2083,14 @@ impl Lang {
                 vec![
                     self.command.package_manager.clone(),
                     "freeze".to_string(),
-                    ">".to_string(),
+                    STDOUT_REDIRECT.to_string(),
                     "requirements.txt".to_string(),
                 ],
             ],
             Kind::NodeJS | Kind::TypeScript | Kind::SvelteKit => {
                 vec![vec![
                     self.command.package_manager.clone(),
-                    "install".to_string(),
+                    self.node_package_manager().add_subcommand().to_string(),
                     PACKAGE_NAME_PLACEHOLDER.to_string(),
                 ]]
             }
@@ -692,6 +2099,17 @@ impl Lang {
                 "add".to_string(),
                 PACKAGE_NAME_PLACEHOLDER.to_string(),
             ]],
+            Kind::CSharp => vec![vec![
+                self.command.package_manager.clone(),
+                "add".to_string(),
+                "package".to_string(),
+                PACKAGE_NAME_PLACEHOLDER.to_string(),
+            ]],
+            Kind::Php => vec![vec![
+                self.command.package_manager.clone(),
+                "require".to_string(),
+                PACKAGE_NAME_PLACEHOLDER.to_string(),
+            ]],
             _ => vec![],
         };
     }
@@ -703,69 +2121,122 @@ impl Lang {
                 "mod".to_string(),
                 "vendor".to_string(),
             ]],
+            Kind::Python if self.python_toolchain() == PythonToolchain::Uv => vec![vec![
+                self.command.package_manager.clone(),
+                "venv".to_string(),
+            ]],
+            Kind::Python if self.python_toolchain() == PythonToolchain::Poetry => vec![vec![
+                self.command.package_manager.clone(),
+                "config".to_string(),
+                "virtualenvs.in-project".to_string(),
+                "true".to_string(),
+                "--local".to_string(),
+            ]],
             Kind::Python => vec![vec![
                 self.command.original_base.clone(),
                 "-m".to_string(),
                 "venv".to_string(),
-                "venv".to_string(),
+                self.python_toolchain().venv_dir_name().to_string(),
             ]],
             _ => vec![],
         };
     }
 
     fn determine_build_command(&mut self) {
-        if self.kind == Kind::TypeScript {
-            self.determine_typescript_build_command();
+        match self.kind {
+            Kind::TypeScript => self.determine_typescript_build_command(),
+            Kind::Java if !self.command.package_manager.is_empty() => {
+                let task = if self.is_maven_project() {
+                    "package"
+                } else {
+                    "build"
+                };
+
+                self.command.build = vec![vec![
+                    self.command.package_manager.clone(),
+                    task.to_string(),
+                ]];
+            }
+            Kind::CSharp if !self.command.package_manager.is_empty() => {
+                self.command.build = vec![vec![
+                    self.command.package_manager.clone(),
+                    "build".to_string(),
+                ]];
+            }
+            _ => {}
         }
     }
 
     fn determine_typescript_build_command(&mut self) {
         self.command.build = Vec::new();
 
+        if self.command.is_offline {
+            self.command.build.push(vec![
+                self.command.package_manager.clone(),
+                "run".to_string(),
+                "build".to_string(),
+            ]);
+            return;
+        }
+
         if let Some(tsc) = self.find_node_command("tsc") {
-            self.command.build.push(vec![tsc]);
+            self.command.build.push(tsc);
         }
 
         if let Some(tsc_alias) = self.find_node_command("tsc-alias") {
-            self.command.build.push(vec![tsc_alias]);
+            self.command.build.push(tsc_alias);
         }
-    }
 
-    fn find_node_command(&self, cmd: &str) -> Option<String> {
-        let candidates = self.build_node_command_candidates(cmd);
+        if self.command.build.is_empty() && !self.command.package_manager.is_empty() {
+            self.command.build.push(vec![
+                self.command.package_manager.clone(),
+                "run".to_string(),
+                "build".to_string(),
+            ]);
+        }
+    }
 
-        self.find_first_available_command_owned(&candidates)
+    fn find_node_command(&self, cmd: &str) -> Option<Vec<String>> {
+        self.build_node_command_candidates(cmd)
+            .into_iter()
+            .find(|candidate| self.is_command_available(&candidate[0]))
     }
 
-    fn build_node_command_candidates(&self, cmd: &str) -> Vec<String> {
+    fn build_node_command_candidates(&self, cmd: &str) -> Vec<Vec<String>> {
         let mut candidates = if self.os.kind == OSKind::Windows {
             vec![
-                format!(
+                vec![format!(
                     ".{}node_modules{}.bin{}{}.cmd",
                     MAIN_SEPARATOR_STR, MAIN_SEPARATOR_STR, MAIN_SEPARATOR_STR, cmd
-                ),
-                format!(
+                )],
+                vec![format!(
                     ".{}node_modules{}.bin{}{}.ps1",
                     MAIN_SEPARATOR_STR, MAIN_SEPARATOR_STR, MAIN_SEPARATOR_STR, cmd
-                ),
+                )],
             ]
         } else {
             vec![]
         };
 
-        let mut npx_commands = Vec::new();
+        let (commands, windows_commands) = self.node_package_manager().runner_commands();
+        let runner_commands = if self.os.kind == OSKind::Windows {
+            windows_commands.iter().chain(commands.iter())
+        } else {
+            commands.iter().chain([].iter())
+        };
 
-        for npx_command in self.build_common_command_candidates(NPX_COMMANDS, NPX_COMMANDS_WINDOWS)
-        {
-            npx_commands.push(format!("{} {}", npx_command, cmd));
+        for runner_command in runner_commands {
+            let mut candidate: Vec<String> =
+                runner_command.iter().map(|arg| arg.to_string()).collect();
+            candidate.push(cmd.to_string());
+            candidates.push(candidate);
         }
 
-        candidates.extend(npx_commands);
-        candidates.push(join_text(
+        candidates.push(vec![join_text(
             &[".", "node_modules", ".bin", cmd],
             MAIN_SEPARATOR_STR,
-        ));
-        candidates.push(cmd.to_string());
+        )]);
+        candidates.push(vec![cmd.to_string()]);
 
         candidates
     }
@@ -776,6 +2247,18 @@ impl Lang {
                 &self.command.base,
                 vec!["run".to_string(), "main.go".to_string()],
             ),
+            Kind::Python if self.python_toolchain() == PythonToolchain::Uv => (
+                &self.command.package_manager,
+                vec!["run".to_string(), "main.py".to_string()],
+            ),
+            Kind::Python if self.python_toolchain() == PythonToolchain::Poetry => (
+                &self.command.package_manager,
+                vec![
+                    "run".to_string(),
+                    "python".to_string(),
+                    "main.py".to_string(),
+                ],
+            ),
             Kind::Python => (&self.command.base, vec!["main.py".to_string()]),
             Kind::TypeScript => (
                 &self.command.base,
@@ -787,6 +2270,23 @@ impl Lang {
                 &self.command.package_manager,
                 vec!["run".to_string(), "dev".to_string()],
             ),
+            Kind::Java if self.is_maven_project() => (
+                &self.command.package_manager,
+                vec![
+                    "compile".to_string(),
+                    "exec:java".to_string(),
+                    format!("-Dexec.mainClass={}.Main", self.prop.module_name),
+                ],
+            ),
+            Kind::Java | Kind::CSharp => (&self.command.package_manager, vec!["run".to_string()]),
+            Kind::Php => (
+                &self.command.base,
+                vec![
+                    "-S".to_string(),
+                    format!("0.0.0.0:{}", self.port()),
+                    "index.php".to_string(),
+                ],
+            ),
             _ => {
                 self.command.running = vec![];
                 return;
@@ -805,11 +2305,16 @@ impl Lang {
         self.command.running = vec![cmd_vec];
     }
 
+    #[inline]
+    fn is_maven_project(&self) -> bool {
+        self.prop.project_dir.join("pom.xml").is_file()
+    }
+
     #[inline]
     fn find_first_available_command(&self, candidates: &[&str]) -> Option<String> {
         candidates
             .iter()
-            .find(|&&cmd| check_command(&self.prop.project_dir, cmd))
+            .find(|&&cmd| self.is_command_available(cmd))
             .map(|&cmd| cmd.to_string())
     }
 
@@ -817,7 +2322,396 @@ impl Lang {
     fn find_first_available_command_owned(&self, candidates: &[String]) -> Option<String> {
         candidates
             .iter()
-            .find(|cmd| check_command(&self.prop.project_dir, cmd))
+            .find(|cmd| self.is_command_available(cmd))
             .cloned()
     }
+
+    #[inline]
+    fn is_command_available(&self, cmd: &str) -> bool {
+        if self.command.is_offline {
+            return !cmd.starts_with('.') && !PROJECT_WRAPPER_COMMANDS.contains(&cmd);
+        }
+
+        check_command(&self.prop.project_dir, cmd)
+    }
+}
+
+fn find_java_package(dir: &Path) -> String {
+    let mut package_dir: PathBuf = JAVA_SRC_DIR_NAMES.iter().collect();
+    let mut names: Vec<String> = Vec::new();
+
+    loop {
+        let dir = dir.join(&package_dir);
+        let Ok(entries) = dir.read_dir() else {
+            break;
+        };
+
+        let paths: Vec<PathBuf> = entries.flatten().map(|entry| entry.path()).collect();
+        let sub_dirs: Vec<&PathBuf> = paths.iter().filter(|path| path.is_dir()).collect();
+        let has_files = paths
+            .iter()
+            .any(|path| path.extension().is_some_and(|e| e == "java"));
+        let has_parts = JAVA_PART_DIR_NAMES
+            .iter()
+            .any(|name| dir.join(name).is_dir());
+
+        if has_files || has_parts || sub_dirs.len() != 1 {
+            break;
+        }
+
+        let name = dirname(sub_dirs[0]);
+        package_dir.push(&name);
+        names.push(name);
+    }
+
+    names.join(".")
+}
+
+fn find_csproj_file(dir: &Path) -> Option<PathBuf> {
+    let mut paths: Vec<PathBuf> = dir
+        .read_dir()
+        .ok()?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file() && path.extension().is_some_and(|e| e == "csproj"))
+        .collect();
+
+    paths.sort();
+    paths.into_iter().next()
+}
+
+#[inline]
+fn java_src_dir_name(package: &str) -> String {
+    let mut names = JAVA_SRC_DIR_NAMES.to_vec();
+    names.extend(package.split('.').filter(|name| !name.is_empty()));
+
+    join_text(&names, MAIN_SEPARATOR_STR)
+}
+
+fn find_php_psr4_root(dir: &Path) -> Option<(String, String)> {
+    let content = read_file(dir.join("composer.json").as_path()).ok()?;
+    let parsed = serde_json::from_str::<Json>(&content).ok()?;
+    let roots = parsed.get("autoload")?.get("psr-4")?.as_object()?;
+
+    roots.iter().find_map(|(namespace, path)| {
+        let namespace = namespace.trim_end_matches('\\');
+        let src_dir_name = path.as_str()?.trim_end_matches('/');
+
+        (!namespace.is_empty()).then(|| (namespace.to_string(), src_dir_name.to_string()))
+    })
+}
+
+fn is_qualified_name(name: &str, separator: char) -> bool {
+    name.split(separator).all(|segment| {
+        let mut chars = segment.chars();
+
+        chars
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+            && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+    })
+}
+
+#[inline]
+fn parse_port(port: &str) -> Option<u16> {
+    port.trim().parse::<u16>().ok().filter(|port| *port > 0)
+}
+
+fn load_answers_file(path: &Path) -> Result<Json, String> {
+    let content = read_file(path).map_err(|e| e.to_string())?;
+    let is_json = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| e.eq_ignore_ascii_case(ANSWERS_FILE_JSON_EXTENSION));
+
+    if is_json {
+        return serde_json::from_str::<Json>(&content)
+            .map_err(|e| format!("Failed to parse JSON at {}: {}", path.display(), e));
+    }
+
+    let parsed = content
+        .parse::<TomlTable>()
+        .map_err(|e| format!("Failed to parse TOML at {}: {}", path.display(), e))?;
+
+    serde_json::to_value(parsed)
+        .map_err(|e| format!("Failed to read answers at {}: {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sys::{create_dir, remove_dir, remove_file, write_file};
+    use std::env::temp_dir;
+    use std::process::id;
+
+    fn preset_with_answers(filename: &str, content: &str) -> (Preset, PathBuf) {
+        let path = temp_dir().join(format!("uwais-answers-{}-{}", id(), filename));
+        write_file(&path, content).unwrap();
+
+        let preset = Preset {
+            answers_file: Some(path.clone()),
+            ..Preset::default()
+        };
+
+        (preset, path)
+    }
+
+    fn lang_with_preset(name: &str, preset: Preset) -> Lang {
+        let mut lang = Lang::new(OS::new(), name);
+        lang.preset = preset;
+
+        lang
+    }
+
+    #[test]
+    fn merges_answers_without_overriding_options() {
+        let (mut preset, path) = preset_with_answers(
+            "merge.toml",
+            r#"
+project_name = "from-answers"
+module_name = "example.com/api"
+use_vendoring = false
+"#,
+        );
+        preset.project_name = Some("from-options".to_string());
+
+        let result = preset.merge_answers_file();
+        remove_file(&path).unwrap();
+
+        result.unwrap();
+        assert_eq!(preset.project_name.as_deref(), Some("from-options"));
+        assert_eq!(preset.module_name.as_deref(), Some("example.com/api"));
+        assert_eq!(preset.is_use_vendoring, Some(false));
+    }
+
+    #[test]
+    fn merges_json_answers() {
+        let (mut preset, path) = preset_with_answers(
+            "merge.json",
+            r#"{"project_name": "api", "python_command": "python3"}"#,
+        );
+
+        let result = preset.merge_answers_file();
+        remove_file(&path).unwrap();
+
+        result.unwrap();
+        assert_eq!(preset.project_name.as_deref(), Some("api"));
+        assert_eq!(preset.python_command.as_deref(), Some("python3"));
+    }
+
+    #[test]
+    fn rejects_unknown_and_invalid_answers() {
+        let (mut preset, path) = preset_with_answers("unknown.toml", "projectname = \"api\"\n");
+        let result = preset.merge_answers_file();
+        remove_file(&path).unwrap();
+        assert_eq!(
+            result.unwrap_err(),
+            format!("Unknown answer `projectname` in `{}`", path.display())
+        );
+
+        let (mut preset, path) = preset_with_answers("invalid.toml", "use_vendoring = \"no\"\n");
+        let result = preset.merge_answers_file();
+        remove_file(&path).unwrap();
+        assert_eq!(
+            result.unwrap_err(),
+            format!(
+                "The answer `use_vendoring` in `{}` has an invalid value",
+                path.display()
+            )
+        );
+    }
+
+    #[test]
+    fn validates_project_name() {
+        let validate = |name: &str| {
+            lang_with_preset(
+                "go",
+                Preset {
+                    project_name: Some(name.to_string()),
+                    ..Preset::default()
+                },
+            )
+            .validate_preset()
+        };
+
+        assert!(lang_with_preset("go", Preset::default())
+            .validate_preset()
+            .is_ok());
+        assert_eq!(validate("").unwrap_err(), "Project name cannot be empty");
+        assert_eq!(
+            validate("my api").unwrap_err(),
+            "Project name cannot contain space"
+        );
+    }
+
+    #[test]
+    fn validates_database_per_language() {
+        let go = Lang::new(OS::new(), "go");
+        assert!(go.validate_database("postgres").is_ok());
+        assert_eq!(
+            go.validate_database("oracle").unwrap_err(),
+            format!(
+                "`oracle` is not a supported database for {}. Available: {}",
+                go.name, "postgres, mysql, sqlite, mongodb, memory"
+            )
+        );
+
+        let java = Lang::new(OS::new(), "java");
+        assert_eq!(
+            java.validate_database("postgres").unwrap_err(),
+            format!(
+                "{} does not support choosing a database, its repositories are in-memory only",
+                java.name
+            )
+        );
+    }
+
+    #[test]
+    fn validates_java_package_name() {
+        let validate = |module: &str| {
+            lang_with_preset(
+                "java",
+                Preset {
+                    module_name: Some(module.to_string()),
+                    ..Preset::default()
+                },
+            )
+            .validate_preset()
+        };
+
+        assert!(validate("com.example.api").is_ok());
+        assert_eq!(
+            validate("com.example.1api").unwrap_err(),
+            "`com.example.1api` is not a valid Java package name"
+        );
+    }
+
+    #[test]
+    fn validates_port() {
+        let validate = |port: &str| {
+            lang_with_preset(
+                "go",
+                Preset {
+                    port: Some(port.to_string()),
+                    ..Preset::default()
+                },
+            )
+            .validate_preset()
+        };
+
+        assert!(validate("8080").is_ok());
+        assert_eq!(validate("0").unwrap_err(), "`0` is not a valid port");
+        assert_eq!(
+            validate("70000").unwrap_err(),
+            "`70000` is not a valid port"
+        );
+    }
+
+    #[test]
+    fn merges_port_answer_given_as_number() {
+        let (mut preset, path) = preset_with_answers("port.toml", "port = 8080\n");
+
+        let result = preset.merge_answers_file();
+        remove_file(&path).unwrap();
+
+        result.unwrap();
+        assert_eq!(preset.port.as_deref(), Some("8080"));
+    }
+
+    #[test]
+    fn validates_git_author() {
+        let validate = |author: &str| {
+            lang_with_preset(
+                "go",
+                Preset {
+                    git_author: Some(author.to_string()),
+                    ..Preset::default()
+                },
+            )
+            .validate_preset()
+        };
+
+        assert!(validate("Jane Doe <jane@example.com>").is_ok());
+        assert_eq!(
+            validate("Jane").unwrap_err(),
+            "`Jane` is not a valid Git author, use 'Name <email>'"
+        );
+    }
+
+    #[test]
+    fn takes_prompt_defaults_when_assuming_yes() {
+        let preset = Preset {
+            is_assume_yes: true,
+            ..Preset::default()
+        };
+
+        let go = lang_with_preset("go", preset.clone());
+        assert_eq!(
+            go.read_choice("HTTP framework", go.http_frameworks()),
+            HttpFramework::Fiber
+        );
+        assert_eq!(
+            go.read_choice("database", go.databases()),
+            Database::Postgres
+        );
+        assert!(!go.ask_to_disable_feature("vendoring", "We use vendoring by default"));
+        assert_eq!(go.read_port(), DEFAULT_PORT);
+
+        let mut java = lang_with_preset("java", preset);
+        java.prop.project_name = "my-app".to_string();
+        assert_eq!(java.read_java_package_name(), "com.example.myapp");
+    }
+
+    #[test]
+    fn builds_node_runner_commands_as_separate_args() {
+        let mut lang = Lang::new(OS::new(), "typescript");
+        lang.os.kind = OSKind::Windows;
+        lang.prop.node_package_manager = NodePackageManager::Pnpm;
+
+        let candidates = lang.build_node_command_candidates("tsc");
+
+        assert!(candidates.contains(&vec![
+            "pnpm.cmd".to_string(),
+            "exec".to_string(),
+            "tsc".to_string()
+        ]));
+        assert!(candidates.contains(&vec![
+            "pnpm".to_string(),
+            "exec".to_string(),
+            "tsc".to_string()
+        ]));
+        assert!(candidates.iter().flatten().all(|arg| !arg.contains(' ')));
+    }
+
+    #[test]
+    fn uses_the_toolchain_venv_dir() {
+        let dir = temp_dir().join(format!("uwais-uv-venv-{}", id()));
+        let _ = remove_dir(&dir);
+        create_dir(dir.join(".venv")).unwrap();
+        write_file(dir.join("uv.lock"), "").unwrap();
+
+        let mut lang = Lang::new(OS::new(), "python");
+        lang.extract_python_props(&dir);
+        remove_dir(&dir).unwrap();
+
+        assert_eq!(lang.prop.python_toolchain, PythonToolchain::Uv);
+        assert!(lang.prop.is_use_vendoring);
+        assert_eq!(
+            lang.build_venv_command("python"),
+            join_text(&[".venv", "bin", "python"], "/")
+        );
+    }
+
+    #[test]
+    fn rejects_uv_without_venv() {
+        let preset = Preset {
+            python_toolchain: Some("uv".to_string()),
+            is_use_vendoring: Some(false),
+            ..Preset::default()
+        };
+
+        let lang = lang_with_preset("python", preset);
+
+        assert!(lang.validate_preset().is_err());
+    }
 }
@@ -1,52 +1,83 @@
 use colored::Colorize;
 use include_dir::DirEntry;
-use std::path::{Component, PathBuf, MAIN_SEPARATOR_STR};
+use serde_json::{json, Value as Json};
+use std::io::{stdout, ErrorKind as IoErrorKind, Write};
+use std::path::{Component, Path, PathBuf, MAIN_SEPARATOR_STR};
 use std::process::exit;
 use std::thread;
 use std::time::Duration;
+use toml_edit::{value, Array, DocumentMut, InlineTable, Item, TableLike};
 
+mod arg;
 mod command;
+mod completion;
 mod exec;
+mod error;
+mod failure;
 mod git;
 mod http;
 mod lang;
 mod os;
 mod print;
 mod read;
+mod route;
 mod source;
 mod string;
 mod structure;
+mod staging;
 mod sys;
 mod time;
 
 use crate::command::Command;
-use crate::exec::{exec_spawn, exec_vec_string_command, vec_string_command_to_colored_string};
-use crate::lang::{Kind as LangKind, Lang};
+use crate::completion::complete;
+use crate::exec::{
+    check_command, exec_spawn, exec_vec_string_command, join_command, STDOUT_REDIRECT,
+};
+use crate::failure::Failure;
+use crate::git::{git_init_commands, is_git_work_tree};
+use crate::lang::{Database, Kind as LangKind, Lang, PythonToolchain, NAMES as LANG_NAMES};
 use crate::os::{Kind as OSKind, OS};
 use crate::print::{
-    print_done, print_err, print_err_with_info, print_help, print_info, print_text, print_warn,
-    print_warn_with_info,
+    print_action_done, print_action_dry_run, print_action_err, print_action_info,
+    print_action_skip, print_action_warn, print_command_help, print_done, print_dry_run, print_err,
+    print_err_with_info, print_help, print_info, print_list, print_text, print_warn,
+    print_warn_with_info, Action,
 };
 use crate::read::{read_args, read_input};
+use crate::route::{find_port, find_routes};
 use crate::source::Source;
+use crate::staging::Staging;
 use crate::string::{to_pascal_case, trim_newline};
 use crate::structure::{
-    Part as StructurePart, Partial as StructurePartial, Structure, Template, EXTENSION_TO_REMOVE,
-    LANGUAGE_EXTENSION_TO_REPLACE, LANGUAGE_NAME_TO_REPLACE, MODULE_NAME_TO_REPLACE,
-    PROJECT_NAME_TO_REPLACE, STRUCTURE_VERSION_TO_REPLACE, TEMPLATE_NAME_PASCAL_CASE_TO_REPLACE,
-    TEMPLATE_NAME_TO_REPLACE, TEMPLATE_PREFIX_FILENAME, VENDORING_SCRIPT_TO_REPLACE,
+    IncludedDir, Part as StructurePart, Partial as StructurePartial, Structure, Template,
+    Version as StructureVersion, DATABASE_DEPENDENCIES_TO_REPLACE, DATABASE_ENV_TO_REPLACE,
+    DATABASE_NAME_PASCAL_CASE_TO_REPLACE, DATABASE_NAME_TO_REPLACE, DATABASE_PACKAGES_TO_REPLACE, DATABASE_SERVICE_TO_REPLACE,
+    EXTENSION_TO_REMOVE,
+    LANGUAGE_EXTENSION_TO_REPLACE, LANGUAGE_NAME_TO_REPLACE, MODULE_NAME_ESCAPED_TO_REPLACE,
+    INFRA_DIR_NAME, MODULE_NAME_TO_REPLACE, PACKAGE_DIR_NAME, PACKAGE_MANAGER_SETUP_TO_REPLACE,
+    PACKAGE_MANAGER_TO_REPLACE, PORT_TO_REPLACE, PROJECT_NAME_TO_REPLACE,
+    SETUP_SCRIPT_PS1_FILENAME, SETUP_SCRIPT_SH_FILENAME, STRUCTURE_VERSION_TO_REPLACE,
+    TEMPLATE_NAME_PASCAL_CASE_TO_REPLACE, TEMPLATE_NAME_TO_REPLACE,
+    TEMPLATE_PASCAL_CASE_PREFIX_FILENAME, TEMPLATE_PREFIX_FILENAME, VENDORING_SCRIPT_TO_REPLACE,
+    path_contains_template, strip_variant_components,
 };
 use crate::sys::{
-    copy_file, create_dir, create_file, cwd, dirname, file_stem, filename, parent_dir,
-    path_to_colored, path_to_str, read_file, remove_dir, remove_file, remove_paths, trim_extension,
-    write_file, VERSION,
+    copy_file, create_dir, create_file, cwd, dirname, file_stem, filename, is_current_dir,
+    parent_dir, path_to_colored, path_to_str, read_file, remove_dir, remove_file, remove_paths,
+    trim_extension, write_file, VERSION,
 };
 
+const LIST_TEMPLATE_NAME: &str = "NAME";
+const LIST_PACKAGE_DIR: &str = "PACKAGE";
+const LIST_PROJECT_NAME: &str = "PROJECT";
+// Characters that sh or PowerShell interpret when they appear in an unquoted argument.
+const SCRIPT_METACHARACTERS: &str = "'\"$`;&|*?[]<>()#~!@{},";
+
 fn main() {
     let os = OS::new();
     if !os.is_valid() {
         print_err("The current OS is not supported");
-        exit(1);
+        exit(Failure::UnsupportedOS.code());
     }
 
     let args = read_args();
@@ -56,40 +87,58 @@ fn main() {
 
     match command {
         Command::Help => print_help(),
+        Command::HelpFor(name, spec) => print_command_help(&name, spec),
         Command::Version => version(),
-        Command::Update(mut source) => handle_update(os, &mut source),
+        Command::Update(mut source) => exit_on_failure(handle_update(os, &mut source)),
         Command::UpdaterTask => handle_updater_task(os),
         Command::UpdaterTaskClearance => handle_updater_task_clearance(os),
         Command::Import(mut partial, mut source, names) => {
-            handle_import(os, &mut partial, &mut source, &names)
+            exit_on_failure(handle_import(os, &mut partial, &mut source, &names))
+        }
+        Command::Add(template, names) => exit_on_failure(handle_add(os, template, &names)),
+        Command::Generator(structure) => exit_on_failure(handle_generator(structure)),
+        Command::List(is_json) => exit_on_failure(handle_list(os, is_json)),
+        Command::Completions(shell) => print!("{}", shell.script()),
+        Command::Complete(line, point) => {
+            for candidate in complete(os, &line, point) {
+                println!("{}", candidate);
+            }
         }
-        Command::Add(template, names) => handle_add(os, template, &names),
-        Command::Generator(structure) => handle_generator(structure),
-        Command::Unknown => {
-            print_err("The command is invalid");
-            exit(1);
+        Command::Unknown(reason) => {
+            print_err_with_info("The command is invalid", &reason);
+            print_text(&format!(
+                "Run `{}` to see the available commands",
+                "uwais --help".bright_cyan()
+            ));
+            exit(Failure::InvalidArguments.code());
         }
     }
 }
 
+#[inline]
+fn exit_on_failure(result: Result<(), Failure>) {
+    if let Err(failure) = result {
+        exit(failure.code());
+    }
+}
+
 #[inline]
 fn version() {
     println!("{}", VERSION);
 }
 
-fn handle_update(os: OS, source: &mut Source) {
+fn handle_update(os: OS, source: &mut Source) -> Result<(), Failure> {
     if !validate_source(source, "Checking latest Uwais source") {
-        exit(1);
+        return Err(Failure::SourceUnavailable);
     }
 
-    let source_filepath = match download_latest_release(source) {
-        Ok(path) => path,
-        Err(_) => exit(1),
-    };
+    let source_filepath = download_latest_release(source)?;
 
-    update_binary(os, &source_filepath, source);
+    update_binary(os, &source_filepath, source)?;
     print_done("Updating Uwais");
     source.clear();
+
+    Ok(())
 }
 
 fn validate_source(source: &Source, text: &str) -> bool {
@@ -103,7 +152,7 @@ fn validate_source(source: &Source, text: &str) -> bool {
     true
 }
 
-fn download_latest_release(source: &mut Source) -> Result<PathBuf, ()> {
+fn download_latest_release(source: &mut Source) -> Result<PathBuf, Failure> {
     let std_text = "Getting the latest update";
 
     print_info(std_text);
@@ -111,14 +160,14 @@ fn download_latest_release(source: &mut Source) -> Result<PathBuf, ()> {
     match source.provide_latest_app_release() {
         Ok(filepath) => Ok(filepath),
         Err(err) => {
-            print_err_with_info(std_text, &err);
+            print_err_with_info(std_text, &err.to_string());
             source.clear();
-            Err(())
+            Err(Failure::SourceUnavailable)
         }
     }
 }
 
-fn update_binary(os: OS, source_filepath: &PathBuf, source: &mut Source) {
+fn update_binary(os: OS, source_filepath: &PathBuf, source: &mut Source) -> Result<(), Failure> {
     let std_text = "Updating Uwais";
 
     print_info(std_text);
@@ -128,18 +177,21 @@ fn update_binary(os: OS, source_filepath: &PathBuf, source: &mut Source) {
         OSKind::Linux | OSKind::MacOS => update_unix_binary(os, source_filepath, std_text, source),
         _ => {
             source.clear();
-            exit(1);
+            Err(Failure::UnsupportedOS)
         }
     }
 }
 
-fn update_windows_binary(os: OS, source_filepath: &PathBuf, std_text: &str, source: &mut Source) {
+fn update_windows_binary(
+    os: OS,
+    source_filepath: &PathBuf,
+    std_text: &str,
+    source: &mut Source,
+) -> Result<(), Failure> {
     let updater_task_filepath = os.get_app_updater_task_filepath_from_main_process();
 
     if let Err(err) = copy_file(source_filepath, &updater_task_filepath) {
-        let err_string = err.to_string();
-
-        if err_string.contains("os error 5") || err_string.contains("Access is denied") {
+        if err.is_permission_denied() {
             print_err("Access denied - Administrator privileges required");
             print_warn("Please run this command as Administrator:");
             print_text("");
@@ -151,11 +203,11 @@ fn update_windows_binary(os: OS, source_filepath: &PathBuf, std_text: &str, sour
             print_text("  Download the latest installer from:");
             print_text("  https://github.com/dalikewara/uwais/releases/latest");
         } else {
-            print_err_with_info(std_text, &err);
+            print_err_with_info(std_text, &err.to_string());
         }
 
         source.clear();
-        exit(1);
+        return Err(Failure::Filesystem);
     }
 
     if !updater_task_filepath.is_file() {
@@ -164,36 +216,45 @@ fn update_windows_binary(os: OS, source_filepath: &PathBuf, std_text: &str, sour
         exit(0);
     }
 
-    let _ = exec_spawn(
+    exec_spawn(
         cwd(),
         &[
             path_to_str(&updater_task_filepath).as_str(),
             "--updater-task",
         ],
-    );
+    )
+    .map(|_| ())
+    .map_err(|err| {
+        print_err_with_info(std_text, &err.to_string());
+        source.clear();
+        Failure::ExternalCommand
+    })
 }
 
-fn update_unix_binary(os: OS, source_filepath: &PathBuf, std_text: &str, source: &mut Source) {
+fn update_unix_binary(
+    os: OS,
+    source_filepath: &PathBuf,
+    std_text: &str,
+    source: &mut Source,
+) -> Result<(), Failure> {
     let updater_task_filepath = os.get_app_updater_task_filepath_from_main_process();
 
     if let Err(err) = copy_file(source_filepath, &updater_task_filepath) {
-        let err_string = err.to_string();
-
-        if err_string.contains("Permission denied") || err_string.contains("os error 13") {
+        if err.is_permission_denied() {
             print_err("Permission denied - Elevated privileges required");
             print_warn("Please run this command with sudo:");
             print_text("");
-            print_text(&format!("  sudo uwais update"));
+            print_text("  sudo uwais update");
             print_text("");
             print_text("Or reinstall using the installation script:");
             print_text("");
             print_text("  curl -sSL https://raw.githubusercontent.com/dalikewara/uwais/master/install.sh | sh");
         } else {
-            print_err_with_info(std_text, &err);
+            print_err_with_info(std_text, &err.to_string());
         }
 
         source.clear();
-        exit(1);
+        return Err(Failure::Filesystem);
     }
 
     if !updater_task_filepath.is_file() {
@@ -202,13 +263,19 @@ fn update_unix_binary(os: OS, source_filepath: &PathBuf, std_text: &str, source:
         exit(0);
     }
 
-    let _ = exec_spawn(
+    exec_spawn(
         cwd(),
         &[
             path_to_str(&updater_task_filepath).as_str(),
             "--updater-task",
         ],
-    );
+    )
+    .map(|_| ())
+    .map_err(|err| {
+        print_err_with_info(std_text, &err.to_string());
+        source.clear();
+        Failure::ExternalCommand
+    })
 }
 
 fn handle_updater_task(os: OS) {
@@ -219,26 +286,21 @@ fn handle_updater_task(os: OS) {
         return;
     }
 
-    loop {
-        match os.kind {
-            OSKind::Windows | OSKind::Linux | OSKind::MacOS => {
-                let target_app_filepath = os.get_app_filepath_from_updater_task_child_process();
-
-                if copy_file(&os.app_path, &target_app_filepath).is_ok() {
-                    let _ = exec_spawn(
-                        cwd(),
-                        &[
-                            path_to_str(&target_app_filepath).as_str(),
-                            "--updater-task-clearance",
-                        ],
-                    );
-                    break;
-                }
+    while let OSKind::Windows | OSKind::Linux | OSKind::MacOS = os.kind {
+        let target_app_filepath = os.get_app_filepath_from_updater_task_child_process();
 
-                thread::sleep(Duration::from_millis(100));
-            }
-            _ => break,
+        if copy_file(&os.app_path, &target_app_filepath).is_ok() {
+            let _ = exec_spawn(
+                cwd(),
+                &[
+                    path_to_str(&target_app_filepath).as_str(),
+                    "--updater-task-clearance",
+                ],
+            );
+            break;
         }
+
+        thread::sleep(Duration::from_millis(100));
     }
 }
 
@@ -250,26 +312,23 @@ fn handle_updater_task_clearance(os: OS) {
         return;
     }
 
-    loop {
-        match os.kind {
-            OSKind::Windows | OSKind::Linux | OSKind::MacOS => {
-                let updater_task_filepath = os.get_app_updater_task_filepath_from_main_process();
-                if !updater_task_filepath.is_file() {
-                    return;
-                }
-
-                let _ = remove_file(&updater_task_filepath);
-                break;
-            }
-            _ => break,
+    if let OSKind::Windows | OSKind::Linux | OSKind::MacOS = os.kind {
+        let updater_task_filepath = os.get_app_updater_task_filepath_from_main_process();
+        if updater_task_filepath.is_file() {
+            let _ = remove_file(&updater_task_filepath);
         }
     }
 }
 
-fn handle_import(os: OS, partial: &mut StructurePartial, source: &mut Source, names: &[String]) {
+fn handle_import(
+    os: OS,
+    partial: &mut StructurePartial,
+    source: &mut Source,
+    names: &[String],
+) -> Result<(), Failure> {
     if names.is_empty() {
         print_done("Nothing to be imported");
-        return;
+        return Ok(());
     }
 
     let current_dir = cwd();
@@ -277,22 +336,22 @@ fn handle_import(os: OS, partial: &mut StructurePartial, source: &mut Source, na
     let mut current_lang = Lang::new_from_dir(os.clone(), current_dir.as_path());
     if !current_lang.is_valid() {
         print_err("The current project language is invalid");
-        return;
+        return Err(Failure::InvalidProject);
     }
 
     if !validate_import_structure(partial) {
-        return;
+        return Err(Failure::InvalidArguments);
     }
 
     let source_dir = match provide_and_validate_source(source) {
         Some(dir) => dir,
-        None => return,
+        None => return Err(Failure::SourceUnavailable),
     };
 
     let mut source_lang = Lang::new_from_dir(os, source_dir.as_path());
 
     if !validate_source_lang(&source_lang, &current_lang, source) {
-        return;
+        return Err(Failure::InvalidProject);
     }
 
     let (source_partial_dir, current_partial_dir) = get_partial_directories(
@@ -304,7 +363,7 @@ fn handle_import(os: OS, partial: &mut StructurePartial, source: &mut Source, na
     );
 
     if !validate_partial_directories(&source_partial_dir, &current_partial_dir, partial, source) {
-        return;
+        return Err(Failure::InvalidProject);
     }
 
     current_lang.compose_prop_from_dir(current_dir.as_path());
@@ -315,6 +374,7 @@ fn handle_import(os: OS, partial: &mut StructurePartial, source: &mut Source, na
     print_info(std_text);
 
     let mut commands_to_exec: Vec<Vec<String>> = Vec::new();
+    let mut is_files_ok = true;
 
     for imported_name in names {
         if imported_name.is_empty() {
@@ -322,7 +382,7 @@ fn handle_import(os: OS, partial: &mut StructurePartial, source: &mut Source, na
             continue;
         }
 
-        process_import_entry(
+        is_files_ok &= process_import_entry(
             partial,
             &source_lang,
             &current_lang,
@@ -333,9 +393,20 @@ fn handle_import(os: OS, partial: &mut StructurePartial, source: &mut Source, na
         );
     }
 
-    execute_commands(&current_partial_dir, commands_to_exec);
+    let is_commands_ok =
+        execute_commands(&current_partial_dir, commands_to_exec, partial.is_dry_run);
     source.clear();
     print_done(std_text);
+
+    if !is_files_ok {
+        return Err(Failure::Filesystem);
+    }
+
+    if !is_commands_ok {
+        return Err(Failure::ExternalCommand);
+    }
+
+    Ok(())
 }
 
 fn validate_import_structure(partial: &StructurePartial) -> bool {
@@ -377,7 +448,7 @@ fn provide_and_validate_source(source: &mut Source) -> Option<PathBuf> {
     let source_dir = match source.provide_dir() {
         Ok(dir) => dir,
         Err(err) => {
-            print_err_with_info(std_text, err.as_str());
+            print_err_with_info(std_text, &err.to_string());
             source.clear();
             return None;
         }
@@ -419,9 +490,9 @@ fn validate_source_lang(source_lang: &Lang, current_lang: &Lang, source: &mut So
 
 fn get_partial_directories(
     partial: &StructurePartial,
-    source_dir: &PathBuf,
+    source_dir: &Path,
     source_lang: &Lang,
-    current_dir: &PathBuf,
+    current_dir: &Path,
     current_lang: &Lang,
 ) -> (PathBuf, PathBuf) {
     let partial_dir = partial.get_dir();
@@ -434,8 +505,8 @@ fn get_partial_directories(
 }
 
 fn validate_partial_directories(
-    source_partial_dir: &PathBuf,
-    current_partial_dir: &PathBuf,
+    source_partial_dir: &Path,
+    current_partial_dir: &Path,
     partial: &StructurePartial,
     source: &mut Source,
 ) -> bool {
@@ -485,7 +556,7 @@ fn process_import_entry(
     current_partial_dir: &PathBuf,
     imported_name: &str,
     commands_to_exec: &mut Vec<Vec<String>>,
-) {
+) -> bool {
     let source_src_dir = parent_dir(source_partial_dir);
     let current_src_dir = parent_dir(current_partial_dir);
     let partial_dir = partial.get_dir();
@@ -499,22 +570,30 @@ fn process_import_entry(
     );
 
     if !source_partial_path.exists() {
-        return;
+        return true;
     }
 
     let partial_path_str = partial_path.to_str().unwrap_or_default();
 
     let mut current_partial_path = current_src_dir.join(&partial_path);
     if current_partial_path.exists() {
+        if partial.is_dry_run {
+            print_dry_run(&format!(
+                "Skip `{}` because it already exists in the current project",
+                path_to_colored(partial_path_str)
+            ));
+            return true;
+        }
+
         let new_current_partial_path = handle_existing_import(
             &current_partial_path,
-            &current_partial_dir,
+            current_partial_dir,
             partial_path_str,
             current_lang,
         );
 
         if current_partial_path == new_current_partial_path {
-            return;
+            return true;
         }
 
         current_partial_path = new_current_partial_path
@@ -530,14 +609,19 @@ fn process_import_entry(
         current_lang,
     );
 
-    process_import_files(&processed_entries, source_lang, current_lang);
+    process_import_files(
+        &processed_entries,
+        source_lang,
+        current_lang,
+        partial.is_dry_run,
+    )
 }
 
 fn resolve_import_path(
     partial: &StructurePartial,
     imported_name: &str,
-    partial_dir: &PathBuf,
-    source_src_dir: &PathBuf,
+    partial_dir: &Path,
+    source_src_dir: &Path,
     source_lang: &Lang,
 ) -> (PathBuf, PathBuf) {
     let mut partial_path = partial_dir.join(imported_name);
@@ -579,7 +663,7 @@ fn resolve_import_path(
 
 fn handle_existing_import(
     current_partial_path: &PathBuf,
-    current_partial_dir: &PathBuf,
+    current_partial_dir: &Path,
     partial_path_str: &str,
     current_lang: &Lang,
 ) -> PathBuf {
@@ -606,7 +690,7 @@ fn handle_existing_import(
 
 fn prompt_for_new_name(
     current_partial_path: &PathBuf,
-    current_partial_dir: &PathBuf,
+    current_partial_dir: &Path,
     current_lang: &Lang,
 ) -> PathBuf {
     print_text("");
@@ -639,8 +723,8 @@ fn prompt_for_new_name(
 }
 
 fn collect_import_entries(
-    source_partial_path: &PathBuf,
-    current_partial_path: &PathBuf,
+    source_partial_path: &Path,
+    current_partial_path: &Path,
     partial: &StructurePartial,
     source_partial_dir: &PathBuf,
     imported_name: &str,
@@ -650,7 +734,10 @@ fn collect_import_entries(
     let mut processed_entries: Vec<[PathBuf; 2]> = Vec::new();
 
     if source_partial_path.is_file() {
-        processed_entries.push([source_partial_path.clone(), current_partial_path.clone()]);
+        processed_entries.push([
+            source_partial_path.to_path_buf(),
+            current_partial_path.to_path_buf(),
+        ]);
     } else if source_partial_path.is_dir() {
         if let Ok(entries) = source_partial_path.read_dir() {
             for entry in entries.flatten() {
@@ -687,12 +774,7 @@ fn collect_dependencies(
             for dependency_path in dependency_paths {
                 processed_entries.push([
                     dependency_path.clone(),
-                    cwd().join(
-                        dependency_path
-                            .strip_prefix(source_src_dir.clone())
-                            .unwrap()
-                            .to_path_buf(),
-                    ),
+                    cwd().join(dependency_path.strip_prefix(&source_src_dir).unwrap()),
                 ]);
             }
 
@@ -708,7 +790,10 @@ fn collect_dependencies(
         }
         Err(err) => print_warn_with_info(
             "Failed to retrieve the dependency file",
-            &format!("{} => This may cause missing dependencies", err),
+            &format!(
+                "{} => The external dependencies of `{}` were not installed",
+                err, imported_name
+            ),
         ),
     }
 }
@@ -717,52 +802,85 @@ fn process_import_files(
     processed_entries: &[[PathBuf; 2]],
     source_lang: &Lang,
     current_lang: &Lang,
-) {
+    is_dry_run: bool,
+) -> bool {
+    let mut is_ok = true;
+
     for [src, dst] in processed_entries {
         if src.is_file() {
-            process_import_file(src, dst, source_lang, current_lang);
-            register_rust_module(dst, current_lang);
+            is_ok &= process_import_file(src, dst, source_lang, current_lang, is_dry_run);
+            register_rust_module(dst, current_lang, is_dry_run);
         } else if src.is_dir() {
-            create_directory_with_feedback(dst);
-            register_rust_module(dst, current_lang);
+            is_ok &= create_directory_with_feedback(dst, is_dry_run);
+            register_rust_module(dst, current_lang, is_dry_run);
         }
     }
+
+    is_ok
 }
 
-fn process_import_file(src: &PathBuf, dst: &PathBuf, source_lang: &Lang, current_lang: &Lang) {
-    print_info(&format!("Create file `{}`", path_to_colored(dst)));
+fn process_import_file(
+    src: &PathBuf,
+    dst: &PathBuf,
+    source_lang: &Lang,
+    current_lang: &Lang,
+    is_dry_run: bool,
+) -> bool {
+    let dst_str = path_to_str(dst);
+
+    print_action_info(Action::CreateFile, &dst_str);
 
     if dst.exists() {
-        print_warn_with_info(
+        print_action_skip(
+            Action::CreateFile,
+            &dst_str,
             &format!("File `{}` already exists", path_to_colored(dst)),
-            "Skipping.",
         );
-        return;
+        return true;
+    }
+
+    if is_dry_run {
+        print_action_dry_run(Action::CreateFile, &dst_str);
+        return true;
     }
 
     let mut content = match read_file(src) {
         Ok(c) => c,
         Err(_) => {
-            print_warn_with_info(
+            print_action_skip(
+                Action::CreateFile,
+                &dst_str,
                 &format!(
                     "Failed to read content from `{}` in the source",
                     path_to_colored(dst)
                 ),
-                "Skipping",
             );
-            return;
+            return false;
         }
     };
 
     content = replace_module_names(content, source_lang, current_lang);
 
     match create_file(dst, &content) {
-        Ok(_) => print_done(&format!("Create file `{}`", path_to_colored(dst))),
+        Ok(_) => {
+            print_action_done(Action::CreateFile, &dst_str);
+            true
+        }
+        Err(err) if err.is_conflict() => {
+            print_action_skip(
+                Action::CreateFile,
+                &dst_str,
+                &format!("File `{}` already exists", path_to_colored(dst)),
+            );
+            true
+        }
         Err(_) => {
-            print_warn_with_info(
+            print_action_skip(
+                Action::CreateFile,
+                &dst_str,
                 &format!("Failed to create the file `{}`", path_to_colored(dst)),
-                "Skipping",
             );
+            false
         }
     }
 }
@@ -788,40 +906,90 @@ fn replace_module_names(mut content: String, source_lang: &Lang, current_lang: &
                 content = content.replace(pattern, replacement);
             }
         }
+
+        if let LangKind::Java = current_lang.kind {
+            for keyword in ["package", "import", "import static"] {
+                for separator in [".", ";"] {
+                    content = content.replace(
+                        &format!("{} {}{}", keyword, source_lang.prop.module_name, separator),
+                        &format!("{} {}{}", keyword, current_lang.prop.module_name, separator),
+                    );
+                }
+            }
+        }
+
+        if let LangKind::Php = current_lang.kind {
+            for keyword in ["namespace", "use", "use function", "use const"] {
+                for separator in ["\\", ";"] {
+                    content = content.replace(
+                        &format!("{} {}{}", keyword, source_lang.prop.module_name, separator),
+                        &format!("{} {}{}", keyword, current_lang.prop.module_name, separator),
+                    );
+                }
+            }
+        }
+
+        if let LangKind::CSharp = current_lang.kind {
+            for keyword in ["namespace", "using", "using static"] {
+                for separator in [".", ";"] {
+                    content = content.replace(
+                        &format!("{} {}{}", keyword, source_lang.prop.module_name, separator),
+                        &format!("{} {}{}", keyword, current_lang.prop.module_name, separator),
+                    );
+                }
+            }
+        }
     }
 
     content
 }
 
-fn create_directory_with_feedback(path: &PathBuf) {
+fn create_directory_with_feedback(path: &PathBuf, is_dry_run: bool) -> bool {
+    let path_str = path_to_str(path);
+
+    if is_dry_run {
+        print_action_dry_run(Action::CreateDir, &path_str);
+        return true;
+    }
+
     match create_dir(path) {
-        Ok(_) => print_done(&format!("Create dir `{}`", path_to_colored(path))),
+        Ok(_) => {
+            print_action_done(Action::CreateDir, &path_str);
+            true
+        }
         Err(_) => {
-            print_warn_with_info(
+            print_action_skip(
+                Action::CreateDir,
+                &path_str,
                 &format!("Failed to create the directory `{}`", path_to_colored(path)),
-                "Skipping",
             );
+            false
         }
     }
 }
 
-fn register_rust_module(dst: &PathBuf, current_lang: &Lang) {
-    if !dst.exists() {
+fn register_rust_module(dst: &PathBuf, current_lang: &Lang, is_dry_run: bool) {
+    if !dst.exists() && !is_dry_run {
         return;
     }
 
     if let LangKind::Rust = current_lang.kind {
-        let (dst_parent_dir, dst_mod_name) = if dst.is_file() {
+        let is_file = if dst.exists() {
+            dst.is_file()
+        } else {
+            dst.extension().is_some()
+        };
+        let (dst_parent_dir, dst_mod_name) = if is_file {
             (parent_dir(dst), file_stem(dst))
         } else {
             (dst.to_path_buf(), dirname(dst))
         };
 
-        update_rust_mod_files(&dst_parent_dir, &dst_mod_name, current_lang);
+        update_rust_mod_files(&dst_parent_dir, &dst_mod_name, current_lang, is_dry_run);
     }
 }
 
-fn update_rust_mod_files(parent_dir: &PathBuf, mod_name: &str, lang: &Lang) {
+fn update_rust_mod_files(parent_dir: &Path, mod_name: &str, lang: &Lang, is_dry_run: bool) {
     if mod_name == "mod" {
         return;
     }
@@ -838,39 +1006,54 @@ fn update_rust_mod_files(parent_dir: &PathBuf, mod_name: &str, lang: &Lang) {
                 continue;
             }
 
+            if is_dry_run {
+                print_action_dry_run(Action::UpdateModFile, &path_to_str(&mod_file_path));
+                continue;
+            }
+
             mod_content = format!("{}\n{}\n", trim_newline(&mod_content), mod_statement);
 
             if write_file(&mod_file_path, &mod_content).is_ok() {
-                print_done(&format!(
-                    "Mod/lib file `{}` inside `{}` has been updated",
-                    path_to_colored(mod_file_name),
-                    path_to_colored(parent_dir)
-                ));
+                print_action_done(Action::UpdateModFile, &path_to_str(&mod_file_path));
             }
         }
     }
 }
 
-fn execute_commands(working_dir: &PathBuf, commands: Vec<Vec<String>>) {
+fn execute_commands(working_dir: &PathBuf, commands: Vec<Vec<String>>, is_dry_run: bool) -> bool {
+    let mut is_ok = true;
+
     for command_vec in commands {
-        let cmd_str = vec_string_command_to_colored_string(&command_vec);
+        let cmd_str = join_command(&command_vec);
+
+        if is_dry_run {
+            print_action_dry_run(Action::Exec, &cmd_str);
+            continue;
+        }
 
-        print_info(&format!("Exec `{}`", cmd_str));
+        print_action_info(Action::Exec, &cmd_str);
 
         match exec_vec_string_command(working_dir, &command_vec) {
-            Ok(_) => print_done(&format!("Exec `{}`", cmd_str)),
-            Err(err) => print_warn_with_info(
-                &format!("Exec `{}`", cmd_str),
-                &format!("{} => This may cause missing dependencies", err),
-            ),
+            Ok(_) => print_action_done(Action::Exec, &cmd_str),
+            Err(err) => {
+                print_action_err(Action::Exec, &cmd_str, &err.to_string());
+                is_ok = false;
+            }
         }
     }
+
+    is_ok
 }
 
-fn handle_generator(mut structure: Structure) {
+fn handle_generator(mut structure: Structure) -> Result<(), Failure> {
+    if let Err(err) = structure.lang.preset.merge_answers_file() {
+        print_err(&err);
+        return Err(Failure::InvalidArguments);
+    }
+
     if let Err(err) = structure.validate() {
         print_err(&err);
-        return;
+        return Err(Failure::InvalidArguments);
     }
 
     print_text("");
@@ -879,9 +1062,9 @@ fn handle_generator(mut structure: Structure) {
 
     display_project_summary(&structure);
 
-    if !confirm_generation() {
+    if !structure.is_confirmed && !structure.is_dry_run && !confirm_generation() {
         print_done("Aborted");
-        return;
+        return Ok(());
     }
 
     let std_text = "Generating";
@@ -892,13 +1075,14 @@ fn handle_generator(mut structure: Structure) {
         Ok(result) => result,
         Err(err) => {
             print_err(&err);
-            return;
+            return Err(Failure::Filesystem);
         }
     };
 
-    let project_path = PathBuf::from(&structure.lang.prop.project_name);
+    let project_path = structure.lang.prop.project_dir().to_path_buf();
+    let is_existing_dir = project_path.is_dir();
 
-    if project_path.is_dir() {
+    if is_existing_dir && structure.lang.preset.project_dir.is_none() {
         print_err_with_info(
             std_text,
             &format!(
@@ -906,21 +1090,156 @@ fn handle_generator(mut structure: Structure) {
                 path_to_colored(&project_path)
             ),
         );
-        return;
+        return Err(Failure::Filesystem);
     }
 
-    if let Err(_) = create_project_directory(&project_path) {
-        return;
+    if is_existing_dir {
+        let conflicts = find_conflicting_entries(
+            &dir_components,
+            &dir_entries,
+            &project_path,
+            &structure.lang.package_dir(),
+            &structure.lang.prop.project_name,
+        );
+        let replaceable_paths: Vec<PathBuf> = find_replaceable_entries(&structure, &project_path)
+            .iter()
+            .map(|path| project_path.join(path))
+            .collect();
+        let setup_script_paths = if structure.is_offline {
+            setup_script_paths(&project_path)
+        } else {
+            Vec::new()
+        };
+        let conflicts: Vec<PathBuf> = conflicts
+            .into_iter()
+            .filter(|path| !replaceable_paths.contains(path))
+            .chain(setup_script_paths.into_iter().filter(|path| path.exists()))
+            .collect();
+        if !conflicts.is_empty() {
+            let conflicts: Vec<String> = conflicts
+                .iter()
+                .map(|path| format!("`{}`", path_to_colored(path)))
+                .collect();
+
+            print_err_with_info(
+                std_text,
+                &format!("These would be overwritten: {}", conflicts.join(", ")),
+            );
+            return Err(Failure::Filesystem);
+        }
+    } else if structure.is_dry_run {
+        print_action_dry_run(Action::CreateDir, &path_to_str(&project_path));
     }
 
-    if !generate_project_files(&structure, &dir_components, &dir_entries, &project_path) {
-        return;
+    let mut staging = if structure.is_dry_run {
+        None
+    } else {
+        Some(create_staging(&structure, &project_path, is_existing_dir)?)
+    };
+    if let Some(staging) = &mut staging {
+        for path in find_replaceable_entries(&structure, &project_path) {
+            staging.overwrite(path);
+        }
+    }
+    let work_path = match &staging {
+        Some(staging) => staging.dir().to_path_buf(),
+        None => project_path.clone(),
+    };
+
+    if !generate_project_files(
+        &structure,
+        &dir_components,
+        &dir_entries,
+        &project_path,
+        &work_path,
+    ) {
+        discard_staging(staging, &project_path);
+        return Err(Failure::Filesystem);
+    }
+
+    structure.lang.prop.set_project_dir(&work_path);
+
+    let mut deferred_commands = structure.is_offline.then(Vec::new);
+    let mut is_commands_ok = execute_post_generation(&structure, &work_path, &mut deferred_commands)
+        && execute_setup_commands(&mut structure, &work_path, &mut deferred_commands);
+    if let Some(commands) = &deferred_commands {
+        is_commands_ok &= write_setup_scripts(&structure, commands, &work_path, &project_path);
     }
 
-    execute_post_generation(&structure, &project_path);
-    execute_setup_commands(&mut structure, &project_path);
-    display_success_message(&structure);
+    structure.lang.prop.set_project_dir(&project_path);
+
+    if !is_commands_ok {
+        discard_staging(staging, &project_path);
+        return Err(Failure::ExternalCommand);
+    }
+
+    let relocation = match &staging {
+        Some(staging) if structure.lang.kind == LangKind::Python => {
+            staging.relocate_references(structure.lang.python_toolchain().venv_dir_name())
+        }
+        _ => Ok(()),
+    };
+    if let Err(err) = relocation {
+        print_err_with_info("Failed to relocate the virtual environment", &err.to_string());
+        discard_staging(staging, &project_path);
+        return Err(Failure::Filesystem);
+    }
+
+    if let Some(staging) = &mut staging {
+        commit_staging(staging)?;
+    }
+    initialize_git_repository(&structure, &project_path)?;
+    if staging.is_some() {
+        display_success_message(&structure, &project_path);
+    }
     print_done(std_text);
+
+    Ok(())
+}
+
+fn initialize_git_repository(structure: &Structure, project_path: &Path) -> Result<(), Failure> {
+    let preset = &structure.lang.preset;
+    if !preset.is_git_init() {
+        return Ok(());
+    }
+
+    let commands =
+        git_init_commands(preset.git_message.as_deref(), preset.git_author.as_deref());
+    if structure.is_dry_run {
+        execute_and_log_commands(&commands, project_path, true);
+        return Ok(());
+    }
+
+    let std_text = "Failed to initialize the Git repository";
+    let kept_text = format!(
+        "The project was kept in `{}` without a Git repository",
+        path_to_colored(project_path)
+    );
+
+    if !check_command(cwd(), "git") {
+        print_err_with_info(std_text, &format!("Git is not installed. {}", kept_text));
+        return Err(Failure::ExternalCommand);
+    }
+
+    if is_git_work_tree(project_path) {
+        print_action_skip(
+            Action::Exec,
+            "git init",
+            &format!(
+                "`{}` is already inside a Git repository",
+                path_to_colored(project_path)
+            ),
+        );
+        return Ok(());
+    }
+
+    if !execute_and_log_commands(&commands, project_path, false) {
+        let _ = remove_dir(project_path.join(".git"));
+        print_err_with_info(std_text, &kept_text);
+        return Err(Failure::ExternalCommand);
+    }
+
+    Ok(())
 }
 
 fn display_project_summary(structure: &Structure) {
@@ -940,6 +1259,46 @@ fn display_project_summary(structure: &Structure) {
         "Project name: {}",
         structure.lang.prop.project_name
     ));
+    if structure.lang.preset.project_dir.is_some() {
+        print_text(&format!(
+            "Directory: {}",
+            path_to_colored(structure.lang.prop.project_dir())
+        ));
+    }
+    if structure.lang.prop.http_framework.is_valid() {
+        print_text(&format!(
+            "HTTP framework: {}",
+            structure.lang.prop.http_framework.title().bright_cyan()
+        ));
+    }
+    if structure.lang.prop.database.is_valid() {
+        print_text(&format!(
+            "Database: {}",
+            structure.lang.prop.database.title().bright_cyan()
+        ));
+    }
+    if structure.lang.prop.node_package_manager.is_valid() {
+        print_text(&format!(
+            "Package manager: {}",
+            structure.lang.prop.node_package_manager.name().bright_cyan()
+        ));
+    }
+    print_text(&format!("Port: {}", structure.lang.port()));
+    print_text(if structure.is_offline {
+        "Offline: yes"
+    } else {
+        "Offline: no"
+    });
+    print_text(if structure.lang.preset.is_git_init() {
+        "Git repository: yes"
+    } else {
+        "Git repository: no"
+    });
+    print_text(if structure.is_minimal {
+        "Example feature: no"
+    } else {
+        "Example feature: yes"
+    });
 
     match structure.lang.kind {
         LangKind::Go => {
@@ -951,16 +1310,33 @@ fn display_project_summary(structure: &Structure) {
             });
         }
         LangKind::Python => {
-            print_text(if structure.lang.prop.is_use_vendoring {
-                "Virtual environment (venv): yes"
-            } else {
-                "Virtual environment (venv): no"
-            });
+            print_text(&format!(
+                "Python toolchain: {}",
+                structure.lang.python_toolchain().name().bright_cyan()
+            ));
+            print_text(&format!(
+                "Virtual environment ({}): {}",
+                structure.lang.python_toolchain().venv_dir_name(),
+                if structure.lang.prop.is_use_vendoring {
+                    "yes"
+                } else {
+                    "no"
+                }
+            ));
             print_text(&format!(
                 "Python command: {}",
                 structure.lang.command.base.bright_cyan()
             ));
         }
+        LangKind::Java => {
+            print_text(&format!("Java package: {}", structure.lang.prop.module_name));
+        }
+        LangKind::CSharp => {
+            print_text(&format!("C# root namespace: {}", structure.lang.prop.module_name));
+        }
+        LangKind::Php => {
+            print_text(&format!("PHP root namespace: {}", structure.lang.prop.module_name));
+        }
         _ => {}
     }
 
@@ -991,82 +1367,222 @@ fn get_structure_components(
     Ok((dir_components, dir_entries))
 }
 
-fn create_project_directory(project_path: &PathBuf) -> Result<(), ()> {
-    match create_dir(project_path) {
-        Ok(_) => {
-            print_done(&format!("Create dir `{}`", path_to_colored(project_path)));
-            Ok(())
+fn create_staging(
+    structure: &Structure,
+    project_path: &PathBuf,
+    is_existing_dir: bool,
+) -> Result<Staging, Failure> {
+    match Staging::new(project_path, structure.is_keep_staging) {
+        Ok(staging) => {
+            if !is_existing_dir {
+                print_action_done(Action::CreateDir, &path_to_str(project_path));
+            }
+            Ok(staging)
         }
         Err(err) => {
-            print_err_with_info(
-                &format!("Create dir `{}`", path_to_colored(project_path)),
-                &err,
+            print_action_err(
+                Action::CreateDir,
+                &path_to_str(project_path),
+                &err.to_string(),
             );
-            let _ = remove_dir(project_path);
-            Err(())
+            Err(Failure::Filesystem)
         }
     }
 }
 
+fn commit_staging(staging: &mut Staging) -> Result<(), Failure> {
+    if let Err(err) = staging.commit() {
+        print_err_with_info("Moving the project into place", &err.to_string());
+        return Err(Failure::Filesystem);
+    }
+
+    Ok(())
+}
+
+fn discard_staging(staging: Option<Staging>, project_path: &PathBuf) {
+    let Some(staging) = staging else {
+        return;
+    };
+
+    if staging.is_kept() {
+        print_warn_with_info(
+            "The staging directory was kept for debugging",
+            &path_to_str(staging.dir()),
+        );
+    }
+
+    print_err_with_info(
+        "Generating",
+        &format!(
+            "Rolled back, nothing was written to `{}`",
+            path_to_colored(project_path)
+        ),
+    );
+}
+
 fn generate_project_files(
     structure: &Structure,
     dir_components: &[Component],
     dir_entries: &[DirEntry],
-    project_path: &PathBuf,
+    project_path: &Path,
+    work_path: &Path,
 ) -> bool {
     for structure_entry in dir_entries {
-        let structure_entry_components: Vec<Component> =
-            structure_entry.path().components().collect();
-        let project_entry_components: Vec<Component> =
-            if structure_entry_components.starts_with(dir_components) {
-                structure_entry_components[dir_components.len()..].to_vec()
+        let package_dir = structure.lang.package_dir();
+        let project_name = &structure.lang.prop.project_name;
+        let (Some(project_entry_path), Some(work_entry_path)) = (
+            resolve_project_entry_path(
+                dir_components,
+                structure_entry,
+                project_path,
+                &package_dir,
+                project_name,
+            ),
+            resolve_project_entry_path(
+                dir_components,
+                structure_entry,
+                work_path,
+                &package_dir,
+                project_name,
+            ),
+        ) else {
+            print_err("Failed to find the project entry parts");
+            return false;
+        };
+
+        if structure.is_dry_run {
+            let action = if structure_entry.as_dir().is_some() {
+                Action::CreateDir
+            } else if project_entry_path.is_file() {
+                Action::UpdateFile
             } else {
-                Vec::new()
+                Action::CreateFile
             };
 
-        if project_entry_components.is_empty() {
-            print_err("Failed to find the project entry parts");
-            let _ = remove_dir(project_path);
-            return false;
-        }
+            if !project_entry_path.is_dir() {
+                print_action_dry_run(action, &path_to_str(&project_entry_path));
+            }
 
-        let project_entry_path = build_project_entry_path(project_path, &project_entry_components);
+            continue;
+        }
 
         if structure_entry.as_dir().is_some() {
-            if let Err(err) = create_dir(&project_entry_path) {
-                print_err_with_info(
-                    &format!("Create dir `{}`", path_to_colored(&project_entry_path)),
-                    &err,
+            if let Err(err) = create_dir(&work_entry_path) {
+                print_action_err(
+                    Action::CreateDir,
+                    &path_to_str(&project_entry_path),
+                    &err.to_string(),
                 );
-                let _ = remove_dir(project_path);
                 return false;
             }
 
-            print_done(&format!(
-                "Create dir `{}`",
-                path_to_colored(&project_entry_path)
-            ));
+            if !project_entry_path.is_dir() {
+                print_action_done(Action::CreateDir, &path_to_str(&project_entry_path));
+            }
 
             continue;
         }
 
-        if !create_project_file(structure_entry, &project_entry_path, structure) {
-            let _ = remove_dir(project_path);
+        if !create_project_file(
+            structure_entry,
+            &work_entry_path,
+            &project_entry_path,
+            structure,
+        ) {
             return false;
         }
     }
     true
 }
 
-fn build_project_entry_path(project_path: &PathBuf, components: &[Component]) -> PathBuf {
-    let mut path = project_path.clone();
+fn find_conflicting_entries(
+    dir_components: &[Component],
+    dir_entries: &[DirEntry],
+    project_path: &Path,
+    package_dir: &str,
+    project_name: &str,
+) -> Vec<PathBuf> {
+    dir_entries
+        .iter()
+        .filter_map(|entry| {
+            let path = resolve_project_entry_path(
+                dir_components,
+                entry,
+                project_path,
+                package_dir,
+                project_name,
+            )?;
+            let is_conflict = if entry.as_dir().is_some() {
+                path.exists() && !path.is_dir()
+            } else {
+                path.exists()
+            };
+
+            is_conflict.then_some(path)
+        })
+        .collect()
+}
+
+fn find_replaceable_entries(structure: &Structure, project_path: &Path) -> Vec<PathBuf> {
+    let manifest = structure
+        .lang
+        .manifest_file_name()
+        .map(PathBuf::from)
+        .filter(|name| project_path.join(name).is_file());
+    let scaffolds = structure
+        .lang
+        .scaffold_files()
+        .iter()
+        .filter(|(name, content)| {
+            read_file(project_path.join(name)).is_ok_and(|existing| existing.trim() == *content)
+        })
+        .map(|(name, _)| PathBuf::from(name));
+
+    manifest.into_iter().chain(scaffolds).collect()
+}
+
+fn resolve_project_entry_path(
+    dir_components: &[Component],
+    structure_entry: &DirEntry,
+    project_path: &Path,
+    package_dir: &str,
+    project_name: &str,
+) -> Option<PathBuf> {
+    let structure_entry_components: Vec<Component> = structure_entry.path().components().collect();
+    let project_entry_components =
+        strip_variant_components(structure_entry_components.strip_prefix(dir_components)?);
+
+    if project_entry_components.is_empty() {
+        return None;
+    }
+
+    Some(build_project_entry_path(
+        project_path,
+        &project_entry_components,
+        package_dir,
+        project_name,
+    ))
+}
+
+fn build_project_entry_path(
+    project_path: &Path,
+    components: &[Component],
+    package_dir: &str,
+    project_name: &str,
+) -> PathBuf {
+    let mut path = project_path.to_path_buf();
 
     for component in components {
         let component_str = component.as_os_str().to_str().unwrap_or_default();
-        if component_str.ends_with(EXTENSION_TO_REMOVE) {
-            path.push(trim_extension(component_str, EXTENSION_TO_REMOVE));
+        if component_str == PACKAGE_DIR_NAME {
+            path.push(package_dir);
+        } else if component_str.ends_with(EXTENSION_TO_REMOVE) {
+            path.push(
+                trim_extension(component_str, EXTENSION_TO_REMOVE)
+                    .replace(PROJECT_NAME_TO_REPLACE, project_name),
+            );
         } else {
-            path.push(component_str);
+            path.push(component_str.replace(PROJECT_NAME_TO_REPLACE, project_name));
         }
     }
 
@@ -1075,6 +1591,7 @@ fn build_project_entry_path(project_path: &PathBuf, components: &[Component]) ->
 
 fn create_project_file(
     structure_entry: &DirEntry,
+    work_entry_path: &PathBuf,
     project_entry_path: &PathBuf,
     structure: &Structure,
 ) -> bool {
@@ -1084,7 +1601,7 @@ fn create_project_file(
             None => {
                 print_err(&format!(
                     "Failed to read file content: {}",
-                    path_to_colored(&structure_entry.path())
+                    path_to_colored(structure_entry.path())
                 ));
                 return false;
             }
@@ -1092,36 +1609,202 @@ fn create_project_file(
         None => {
             print_err(&format!(
                 "Failed to get file: {}",
-                path_to_colored(&structure_entry.path())
+                path_to_colored(structure_entry.path())
             ));
             return false;
         }
     };
 
-    let processed_content = replace_template_placeholders(content, structure);
+    let mut processed_content = replace_template_placeholders(content, structure);
+    let action = if project_entry_path.is_file() {
+        Action::UpdateFile
+    } else {
+        Action::CreateFile
+    };
+
+    let is_manifest = structure
+        .lang
+        .manifest_file_name()
+        .is_some_and(|name| project_entry_path.ends_with(name));
+    if is_manifest && project_entry_path.is_file() {
+        let merged = read_file(project_entry_path)
+            .map_err(|err| err.to_string())
+            .and_then(|existing| merge_manifest(&existing, &processed_content));
+        match merged {
+            Ok(merged) => processed_content = merged,
+            Err(err) => {
+                print_action_err(Action::UpdateFile, &path_to_str(project_entry_path), &err);
+                return false;
+            }
+        }
+    }
 
-    match create_file(project_entry_path, &processed_content) {
+    match create_file(work_entry_path, &processed_content) {
         Ok(_) => {
-            print_done(&format!(
-                "Create file `{}`",
-                path_to_colored(project_entry_path)
-            ));
+            print_action_done(action, &path_to_str(project_entry_path));
             true
         }
         Err(err) => {
-            print_err_with_info(
-                &format!("Create file `{}`", path_to_colored(project_entry_path)),
-                &err,
-            );
+            print_action_err(action, &path_to_str(project_entry_path), &err.to_string());
             false
         }
     }
 }
 
-fn replace_template_placeholders(mut content: String, structure: &Structure) -> String {
-    content = content.replace(STRUCTURE_VERSION_TO_REPLACE, &structure.version.name());
+fn merge_manifest(existing: &str, rendered: &str) -> Result<String, String> {
+    let mut document = existing
+        .parse::<DocumentMut>()
+        .map_err(|err| format!("Failed to parse the existing manifest: {}", err))?;
+    let template = rendered
+        .parse::<DocumentMut>()
+        .map_err(|err| format!("Failed to parse the manifest template: {}", err))?;
+
+    for (key, item) in template.iter().filter(|(key, _)| *key != "package") {
+        merge_manifest_item(document.as_table_mut(), key, item);
+    }
+
+    Ok(document.to_string())
+}
+
+fn merge_manifest_item(table: &mut dyn TableLike, key: &str, item: &Item) {
+    let Some(existing) = table.get_mut(key) else {
+        table.insert(key, item.clone());
+        return;
+    };
+
+    if let (Some(existing), Some(template)) = (existing.as_table_like_mut(), item.as_table_like()) {
+        if key.ends_with("dependencies") {
+            for (name, dependency) in template.iter() {
+                match existing.get_mut(name) {
+                    Some(existing) => merge_manifest_dependency(existing, dependency),
+                    None => {
+                        existing.insert(name, dependency.clone());
+                    }
+                }
+            }
+        } else {
+            for (key, item) in template.iter() {
+                merge_manifest_item(existing, key, item);
+            }
+        }
+    }
+}
+
+fn merge_manifest_dependency(existing: &mut Item, template: &Item) {
+    let version = match (manifest_version(existing), manifest_version(template)) {
+        (Some(current), Some(required)) if is_version_lower(current, required) => {
+            Some(required.to_string())
+        }
+        _ => None,
+    };
+    let current_features = manifest_features(existing);
+    let missing_features: Vec<&str> = manifest_features(template)
+        .into_iter()
+        .filter(|feature| !current_features.contains(feature))
+        .collect();
+    let missing_features: Vec<String> = missing_features.into_iter().map(String::from).collect();
+
+    if let Some(current) = existing.as_str() {
+        if missing_features.is_empty() {
+            if let Some(version) = version {
+                *existing = value(version);
+            }
+            return;
+        }
+
+        let mut dependency = InlineTable::new();
+        dependency.insert("version", version.as_deref().unwrap_or(current).into());
+        *existing = value(dependency);
+    } else if let (Some(version), Some(dependency)) = (version, existing.as_table_like_mut()) {
+        dependency.insert("version", value(version));
+    }
+
+    let Some(dependency) = existing.as_table_like_mut() else {
+        return;
+    };
+    match dependency.get_mut("features").and_then(Item::as_array_mut) {
+        Some(features) => features.extend(missing_features),
+        None if !missing_features.is_empty() => {
+            dependency.insert("features", value(Array::from_iter(missing_features)));
+        }
+        None => {}
+    }
+}
+
+#[inline]
+fn manifest_version(dependency: &Item) -> Option<&str> {
+    dependency
+        .as_str()
+        .or_else(|| dependency.get("version")?.as_str())
+}
+
+#[inline]
+fn manifest_features(dependency: &Item) -> Vec<&str> {
+    dependency
+        .get("features")
+        .and_then(Item::as_array)
+        .map(|features| features.iter().filter_map(|f| f.as_str()).collect())
+        .unwrap_or_default()
+}
+
+fn is_version_lower(current: &str, required: &str) -> bool {
+    let parse = |version: &str| -> Vec<u64> {
+        version
+            .trim_start_matches(['^', '~', '=', ' '])
+            .split('.')
+            .map_while(|part| part.parse().ok())
+            .collect()
+    };
+
+    parse(current) < parse(required)
+}
+
+fn replace_template_placeholders(mut content: String, structure: &Structure) -> String {
+    let database = structure.lang.database();
+    let project_name = &structure.lang.prop.project_name;
+    let database_packages: String = structure
+        .lang
+        .database_packages(database)
+        .iter()
+        .map(|package| format!("{}\n", package))
+        .collect();
+
+    content = content.replace(DATABASE_NAME_TO_REPLACE, database.name());
+    content = content.replace(DATABASE_NAME_PASCAL_CASE_TO_REPLACE, database.pascal_case_name());
+    content = content.replace(
+        DATABASE_ENV_TO_REPLACE,
+        &format_database_env(database, project_name),
+    );
+    content = content.replace(
+        DATABASE_SERVICE_TO_REPLACE,
+        &database.compose_service(project_name),
+    );
+    content = content.replace(DATABASE_PACKAGES_TO_REPLACE, &database_packages);
+    content = content.replace(
+        DATABASE_DEPENDENCIES_TO_REPLACE,
+        &structure
+            .lang
+            .database_manifest_entries(database)
+            .iter()
+            .map(|entry| format!("{}\n", entry))
+            .collect::<String>(),
+    );
+    content = content.replace(
+        PACKAGE_MANAGER_TO_REPLACE,
+        structure.lang.node_package_manager().name(),
+    );
+    content = content.replace(
+        PACKAGE_MANAGER_SETUP_TO_REPLACE,
+        structure.lang.package_manager_setup(),
+    );
+    content = content.replace(STRUCTURE_VERSION_TO_REPLACE, structure.version.name());
     content = content.replace(PROJECT_NAME_TO_REPLACE, &structure.lang.prop.project_name);
     content = content.replace(MODULE_NAME_TO_REPLACE, &structure.lang.prop.module_name);
+    content = content.replace(PORT_TO_REPLACE, &structure.lang.port().to_string());
+    content = content.replace(
+        MODULE_NAME_ESCAPED_TO_REPLACE,
+        &structure.lang.prop.module_name.replace('\\', "\\\\"),
+    );
     content = content.replace(LANGUAGE_NAME_TO_REPLACE, &structure.lang.structure_dir_name);
     content = content.replace(
         LANGUAGE_EXTENSION_TO_REPLACE,
@@ -1140,26 +1823,39 @@ fn replace_template_placeholders(mut content: String, structure: &Structure) ->
     content
 }
 
-fn execute_post_generation(structure: &Structure, project_path: &PathBuf) {
-    for command_vec in &structure.command_post_generation {
-        let cmd_str = vec_string_command_to_colored_string(command_vec);
-        print_info(&format!("Exec `{}`", cmd_str));
+fn format_database_env(database: Database, project_name: &str) -> String {
+    database
+        .env_vars(project_name)
+        .iter()
+        .map(|(key, value)| format!("{}={}\n", key, value))
+        .collect()
+}
 
-        match exec_vec_string_command(project_path, command_vec) {
-            Ok(_) => print_done(&format!("Exec `{}`", cmd_str)),
-            Err(err) => print_warn_with_info(
-                &format!("Exec `{}`", cmd_str),
-                &format!(
-                    "{} => This may cause the project not configures well and not working",
-                    err
-                ),
-            ),
-        }
-    }
+fn execute_post_generation(
+    structure: &Structure,
+    project_path: &Path,
+    deferred_commands: &mut Option<Vec<Vec<String>>>,
+) -> bool {
+    execute_or_defer_commands(
+        &structure.command_post_generation,
+        project_path,
+        structure.is_dry_run,
+        deferred_commands,
+    )
 }
 
-fn execute_setup_commands(structure: &mut Structure, project_path: &PathBuf) {
+fn execute_setup_commands(
+    structure: &mut Structure,
+    project_path: &Path,
+    deferred_commands: &mut Option<Vec<Vec<String>>>,
+) -> bool {
     let mut commands_to_execute: Vec<Vec<String>> = Vec::new();
+    let is_dry_run = structure.is_dry_run;
+    let mut is_ok = true;
+    let database_packages = structure.lang.database_packages(structure.lang.database());
+    let database_install_commands = structure
+        .lang
+        .generate_package_install_commands(&database_packages);
 
     match structure.lang.kind {
         LangKind::Go => {
@@ -1168,6 +1864,7 @@ fn execute_setup_commands(structure: &mut Structure, project_path: &PathBuf) {
                     .lang
                     .generate_module_init_commands(&structure.lang.prop.module_name),
             );
+            commands_to_execute.extend(database_install_commands.clone());
             commands_to_execute.extend(structure.lang.command.dependency_install.clone());
             if structure.lang.prop.is_use_vendoring {
                 commands_to_execute.extend(structure.lang.command.vendoring.clone());
@@ -1175,83 +1872,274 @@ fn execute_setup_commands(structure: &mut Structure, project_path: &PathBuf) {
         }
         LangKind::Python => {
             if structure.lang.prop.is_use_vendoring {
-                execute_and_log_commands(&structure.lang.command.vendoring, project_path);
+                is_ok &= execute_or_defer_commands(
+                    &structure.lang.command.vendoring,
+                    project_path,
+                    is_dry_run,
+                    deferred_commands,
+                );
                 structure.lang.compose_command();
             }
+            if structure.lang.python_toolchain() != PythonToolchain::Pip {
+                commands_to_execute.extend(database_install_commands.clone());
+            }
             commands_to_execute.extend(structure.lang.command.dependency_install.clone());
         }
         LangKind::TypeScript => {
-            execute_and_log_commands(&structure.lang.command.dependency_install, project_path);
+            is_ok &= execute_or_defer_commands(
+                &structure.lang.command.dependency_install,
+                project_path,
+                is_dry_run,
+                deferred_commands,
+            );
             structure.lang.compose_command();
+            commands_to_execute.extend(database_install_commands.clone());
             commands_to_execute.extend(structure.lang.command.build.clone());
         }
         LangKind::NodeJS => {
             commands_to_execute.extend(structure.lang.command.dependency_install.clone());
+            commands_to_execute.extend(database_install_commands.clone());
             commands_to_execute.extend(structure.lang.command.build.clone());
         }
-        LangKind::Rust => {
+        LangKind::SvelteKit | LangKind::Php => {
+            commands_to_execute.extend(structure.lang.command.dependency_install.clone());
+        }
+        LangKind::Java | LangKind::CSharp => {
             commands_to_execute.extend(
                 structure
                     .lang
                     .generate_module_init_commands(&structure.lang.prop.module_name),
             );
-            let packages = vec![
-                "chrono",
-                "once_cell",
-                "actix_web",
-                "serde --features derive",
-                "serde_json",
-                "async_trait",
-            ];
-            commands_to_execute.extend(structure.lang.generate_package_install_commands(&packages));
+            commands_to_execute.extend(structure.lang.command.build.clone());
+        }
+        LangKind::Rust => {
+            commands_to_execute.extend(structure.lang.command.dependency_install.clone());
         }
         _ => {}
     }
 
-    execute_and_log_commands(&commands_to_execute, project_path);
+    is_ok
+        & execute_or_defer_commands(
+            &commands_to_execute,
+            project_path,
+            is_dry_run,
+            deferred_commands,
+        )
+}
+
+fn execute_or_defer_commands(
+    commands: &[Vec<String>],
+    project_path: &Path,
+    is_dry_run: bool,
+    deferred_commands: &mut Option<Vec<Vec<String>>>,
+) -> bool {
+    let Some(deferred_commands) = deferred_commands else {
+        return execute_and_log_commands(commands, project_path, is_dry_run);
+    };
+
+    for command_vec in commands {
+        if command_vec.first().is_some_and(|name| !name.trim().is_empty()) {
+            deferred_commands.push(command_vec.clone());
+        } else {
+            print_action_skip(
+                Action::Exec,
+                &join_command(command_vec),
+                "The command is not available, so it cannot be written into the setup script",
+            );
+        }
+    }
+
+    true
+}
+
+#[inline]
+fn setup_script_paths(project_path: &Path) -> Vec<PathBuf> {
+    [SETUP_SCRIPT_SH_FILENAME, SETUP_SCRIPT_PS1_FILENAME]
+        .iter()
+        .map(|filename| project_path.join(INFRA_DIR_NAME).join(filename))
+        .collect()
 }
 
-fn execute_and_log_commands(commands: &Vec<Vec<String>>, project_path: &PathBuf) {
+fn write_setup_scripts(
+    structure: &Structure,
+    commands: &[Vec<String>],
+    work_path: &Path,
+    project_path: &Path,
+) -> bool {
+    let (sh_commands, ps1_commands) = if structure.lang.is_windows() {
+        let sh_commands = collect_deferred_commands(structure, work_path, OSKind::Linux);
+        (sh_commands, commands.to_vec())
+    } else {
+        let ps1_commands = collect_deferred_commands(structure, work_path, OSKind::Windows);
+        (commands.to_vec(), ps1_commands)
+    };
+    let scripts = [
+        (SETUP_SCRIPT_SH_FILENAME, render_setup_sh(&sh_commands)),
+        (SETUP_SCRIPT_PS1_FILENAME, render_setup_ps1(&ps1_commands)),
+    ];
+    let mut is_ok = true;
+
+    for (filename, content) in scripts {
+        let script_str = path_to_str(project_path.join(INFRA_DIR_NAME).join(filename));
+
+        if structure.is_dry_run {
+            print_action_dry_run(Action::CreateFile, &script_str);
+            continue;
+        }
+
+        match create_file(work_path.join(INFRA_DIR_NAME).join(filename), &content) {
+            Ok(_) => print_action_done(Action::CreateFile, &script_str),
+            Err(err) => {
+                print_action_err(Action::CreateFile, &script_str, &err.to_string());
+                is_ok = false;
+            }
+        }
+    }
+
+    is_ok
+}
+
+fn collect_deferred_commands(
+    structure: &Structure,
+    work_path: &Path,
+    os_kind: OSKind,
+) -> Vec<Vec<String>> {
+    let mut structure = structure.clone();
+    structure.lang = structure.lang.for_os(os_kind);
+    let mut deferred_commands = Some(Vec::new());
+
+    execute_post_generation(&structure, work_path, &mut deferred_commands);
+    execute_setup_commands(&mut structure, work_path, &mut deferred_commands);
+
+    deferred_commands.unwrap_or_default()
+}
+
+fn render_setup_sh(commands: &[Vec<String>]) -> String {
+    let mut content = String::from("#!/bin/sh\n\nset -e\n\ncd \"$(dirname \"$0\")/..\"\n\n");
+
+    for command_vec in commands {
+        content.push_str(&quote_script_command(command_vec, "'\\''"));
+        content.push('\n');
+    }
+
+    content
+}
+
+fn render_setup_ps1(commands: &[Vec<String>]) -> String {
+    let mut content = String::from("$ErrorActionPreference = \"Stop\"\n\n");
+    content.push_str("Set-Location (Join-Path $PSScriptRoot \"..\")\n");
+
     for command_vec in commands {
-        let cmd_str = vec_string_command_to_colored_string(&command_vec);
+        content.push_str(&format!(
+            "\n& {}\nif ($LASTEXITCODE -ne 0) {{ exit $LASTEXITCODE }}\n",
+            quote_script_command(command_vec, "''")
+        ));
+    }
 
-        print_info(&format!("Exec `{}`", cmd_str));
+    content
+}
 
-        match exec_vec_string_command(project_path, &command_vec) {
-            Ok(_) => print_done(&format!("Exec `{}`", cmd_str)),
-            Err(err) => print_warn_with_info(
-                &format!("Exec `{}`", cmd_str),
-                &format!(
-                    "{} => This may cause the project not configures well and not working",
-                    err
-                ),
-            ),
+fn quote_script_command(command_vec: &[String], escaped_quote: &str) -> String {
+    command_vec
+        .iter()
+        .map(|arg| {
+            if arg == STDOUT_REDIRECT {
+                return ">".to_string();
+            }
+
+            let is_quoted = arg.is_empty()
+                || arg.contains(|c: char| c.is_whitespace() || SCRIPT_METACHARACTERS.contains(c));
+            if is_quoted {
+                format!("'{}'", arg.replace('\'', escaped_quote))
+            } else {
+                arg.clone()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn execute_and_log_commands(
+    commands: &[Vec<String>],
+    project_path: &Path,
+    is_dry_run: bool,
+) -> bool {
+    let mut is_ok = true;
+
+    for command_vec in commands {
+        let cmd_str = join_command(command_vec);
+
+        if is_dry_run {
+            print_action_dry_run(Action::Exec, &cmd_str);
+            continue;
+        }
+
+        print_action_info(Action::Exec, &cmd_str);
+
+        match exec_vec_string_command(project_path, command_vec) {
+            Ok(_) => print_action_done(Action::Exec, &cmd_str),
+            Err(err) => {
+                print_action_err(Action::Exec, &cmd_str, &err.to_string());
+                is_ok = false;
+            }
         }
     }
+
+    is_ok
 }
 
-fn display_success_message(structure: &Structure) {
-    let running_scripts: Vec<String> = structure
+fn display_success_message(structure: &Structure, project_path: &Path) {
+    let mut running_scripts: Vec<String> = structure
         .lang
         .command
         .running
         .iter()
         .map(|script| script.join(" "))
         .collect();
+    if running_scripts.is_empty() {
+        running_scripts.push("./infra/start.sh".to_string());
+    }
+    let port = find_port(project_path.join("docker-compose.yml")).unwrap_or(structure.lang.port());
+    let base_url = format!("http://localhost:{}", port);
 
     print_text("");
     print_info("Project Generated");
     print_text("");
-    print_text("To get started, you can enter to your project directory:");
-    print_text("");
-    print_text(&format!(
-        "{:>4}{} {}",
-        " ",
-        "cd".bright_cyan(),
-        structure.lang.prop.project_name.bright_cyan()
-    ));
-    print_text("");
-    print_text("then, run your application (for example):");
+
+    let project_dir = structure.lang.prop.project_dir();
+    let is_in_project_dir = is_current_dir(project_dir);
+    if !is_in_project_dir {
+        print_text("To get started, you can enter to your project directory:");
+        print_text("");
+        print_text(&format!(
+            "{:>4}{} {}",
+            " ",
+            "cd".bright_cyan(),
+            path_to_str(project_dir).bright_cyan()
+        ));
+        print_text("");
+    }
+    if structure.is_offline {
+        let setup_script = if structure.lang.is_windows() {
+            "powershell -ExecutionPolicy Bypass -File infra\\setup.ps1"
+        } else {
+            "sh infra/setup.sh"
+        };
+
+        print_text(if is_in_project_dir {
+            "To get started, run the deferred setup commands:"
+        } else {
+            "then, run the deferred setup commands:"
+        });
+        print_text("");
+        print_text(&format!("{:>4}{}", " ", setup_script.bright_cyan()));
+        print_text("");
+        print_text("then, run your application (for example):");
+    } else if is_in_project_dir {
+        print_text("To get started, run your application (for example):");
+    } else {
+        print_text("then, run your application (for example):");
+    }
     print_text("");
     print_text(&format!(
         "{:>4}{}",
@@ -1261,11 +2149,30 @@ fn display_success_message(structure: &Structure) {
     print_text("");
     print_text("Once the application is running, it will be available at:");
     print_text("");
-    print_text(&format!(
-        "{:>4}{}",
-        " ",
-        "http://localhost:8080".bright_blue()
-    ));
+    print_text(&format!("{:>4}{}", " ", base_url.bright_blue()));
+    print_text("");
+
+    let routes = find_routes(project_path.join(structure.lang.routes_path()));
+    let Some(example_route) = routes
+        .iter()
+        .find(|route| route.method.is_none_or(|method| method == "GET"))
+    else {
+        return;
+    };
+
+    print_text("The example feature serves these routes:");
+    print_text("");
+    for route in &routes {
+        print_text(&format!(
+            "{:>4}{}{}",
+            " ",
+            route
+                .method
+                .map(|method| format!("{:<6} ", method).bright_cyan().to_string())
+                .unwrap_or_default(),
+            format!("{}{}", base_url, route.path).bright_blue()
+        ));
+    }
     print_text("");
     print_text("For example, you can try:");
     print_text("");
@@ -1273,27 +2180,149 @@ fn display_success_message(structure: &Structure) {
         "{:>4}{} {}",
         " ",
         "curl".bright_cyan(),
-        "http://localhost:8080/todos".bright_blue()
+        format!("{}{}", base_url, example_route.path).bright_blue()
     ));
     print_text("");
 }
 
-fn handle_add(_os: OS, mut template: Template, names: &[String]) {
+fn handle_list(os: OS, is_json: bool) -> Result<(), Failure> {
+    let mut languages: Vec<Lang> = Vec::new();
+
+    for name in LANG_NAMES {
+        let lang = Lang::new(os.clone(), name);
+        if lang.is_valid() && !languages.iter().any(|l| l.kind == lang.kind) {
+            languages.push(lang);
+        }
+    }
+
+    let mut lang_list: Vec<Json> = Vec::new();
+
+    for lang in languages {
+        let aliases: Vec<&str> = LANG_NAMES
+            .iter()
+            .copied()
+            .filter(|name| Lang::new(os.clone(), name).kind == lang.kind)
+            .collect();
+
+        let versions: Vec<Json> = StructureVersion::ALL
+            .iter()
+            .filter(|version| IncludedDir::find(&lang, **version).is_some())
+            .map(|version| list_version_files(&lang, *version))
+            .collect();
+        if versions.is_empty() {
+            continue;
+        }
+
+        lang_list.push(json!({
+            "name": lang.name,
+            "aliases": aliases,
+            "versions": versions,
+        }));
+    }
+
+    let list = json!({
+        "languages": lang_list,
+        "versions": StructureVersion::ALL.iter().map(|v| v.name()).collect::<Vec<_>>(),
+        "default_version": StructureVersion::default().name(),
+    });
+
+    let mut out = stdout().lock();
+    let result = if is_json {
+        writeln!(out, "{}", list)
+    } else {
+        print_list(&mut out, &list)
+    };
+
+    match result {
+        Err(err) if err.kind() != IoErrorKind::BrokenPipe => {
+            print_err_with_info("Failed to print the list", &err.to_string());
+            Err(Failure::Filesystem)
+        }
+        _ => Ok(()),
+    }
+}
+
+fn list_version_files(lang: &Lang, version: StructureVersion) -> Json {
+    let structure = Structure::new(version, lang.clone());
+    let structure_files = match get_structure_components(&structure) {
+        Ok((dir_components, dir_entries)) => dir_entries
+            .iter()
+            .filter(|entry| entry.as_file().is_some())
+            .filter_map(|entry| {
+                resolve_project_entry_path(
+                    &dir_components,
+                    entry,
+                    &PathBuf::new(),
+                    LIST_PACKAGE_DIR,
+                    LIST_PROJECT_NAME,
+                )
+                .map(path_to_str)
+            })
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+
+    let mut templates = serde_json::Map::new();
+
+    for part in StructurePart::ALL {
+        let template = Template::new(version, *part, lang.clone());
+        let template_files: Vec<String> = match get_template_components(&template) {
+            Ok((dir_components, dir_entries)) => dir_entries
+                .iter()
+                .filter_map(|entry| {
+                    let components: Vec<Component> = entry.path().components().collect();
+                    let relative =
+                        strip_variant_components(components.strip_prefix(dir_components.as_slice())?);
+                    let (filename, _) =
+                        extract_template_content(&template, entry, LIST_TEMPLATE_NAME)?;
+
+                    Some(path_to_str(
+                        PathBuf::from(build_template_entry_dir(
+                            &relative,
+                            LIST_TEMPLATE_NAME,
+                            LIST_PACKAGE_DIR,
+                        ))
+                        .join(filename),
+                    ))
+                })
+                .collect(),
+            Err(_) => Vec::new(),
+        };
+
+        templates.insert(part.name().to_string(), json!(template_files));
+    }
+
+    json!({
+        "version": version.name(),
+        "structure": structure_files,
+        "templates": templates,
+    })
+}
+
+fn handle_add(_os: OS, mut template: Template, names: &[String]) -> Result<(), Failure> {
     if names.is_empty() {
         print_done("Nothing to be added");
-        return;
+        return Ok(());
     }
 
+    let std_text = "Adding template(s)";
+
     if let Err(err) = template.validate() {
         print_err(&err);
-        return;
+        return Err(Failure::InvalidProject);
+    }
+
+    if let Err(err) = template.validate_preset() {
+        print_err_with_info(std_text, &err);
+        return Err(Failure::InvalidArguments);
     }
 
     let current_dir = cwd();
 
     template.lang.compose_prop_from_dir(&current_dir);
-
-    let std_text = "Adding template(s)";
+    if let Some(name) = &template.lang.preset.database {
+        template.lang.prop.database = template.lang.find_database(name).unwrap_or_default();
+    }
 
     print_info(std_text);
 
@@ -1301,7 +2330,7 @@ fn handle_add(_os: OS, mut template: Template, names: &[String]) {
         Ok(result) => result,
         Err(err) => {
             print_err(&err);
-            return;
+            return Err(Failure::Filesystem);
         }
     };
 
@@ -1322,11 +2351,226 @@ fn handle_add(_os: OS, mut template: Template, names: &[String]) {
             &mut created_target_paths,
         ) {
             remove_paths(created_target_paths);
-            return;
+            return Err(Failure::Filesystem);
+        }
+    }
+
+    if template.lang.prop.database.is_valid() {
+        if !install_template_database_packages(&template, &current_dir) {
+            remove_paths(created_target_paths);
+            return Err(Failure::ExternalCommand);
         }
+
+        setup_template_database(&template, &current_dir);
     }
 
     print_done(std_text);
+
+    Ok(())
+}
+
+fn install_template_database_packages(template: &Template, current_dir: &Path) -> bool {
+    let database = template.lang.prop.database;
+    let entries = template.lang.database_manifest_entries(database);
+    if !entries.is_empty() && !merge_template_database_dependencies(template, current_dir, &entries)
+    {
+        return false;
+    }
+
+    let packages = template.lang.database_packages(database);
+
+    execute_and_log_commands(
+        &template.lang.generate_package_install_commands(&packages),
+        current_dir,
+        template.is_dry_run,
+    )
+}
+
+fn merge_template_database_dependencies(
+    template: &Template,
+    current_dir: &Path,
+    entries: &[&str],
+) -> bool {
+    let Some(manifest_file_name) = template.lang.manifest_file_name() else {
+        return true;
+    };
+
+    let path = current_dir.join(manifest_file_name);
+    let path_str = path_to_str(&path);
+    let existing = match read_file(&path) {
+        Ok(existing) => existing,
+        Err(err) => {
+            print_action_err(Action::UpdateFile, &path_str, &err.to_string());
+            return false;
+        }
+    };
+    let rendered = format!("[dependencies]\n{}\n", entries.join("\n"));
+    let merged = match merge_manifest(&existing, &rendered) {
+        Ok(merged) => merged,
+        Err(err) => {
+            print_action_err(Action::UpdateFile, &path_str, &err);
+            return false;
+        }
+    };
+
+    if merged == existing {
+        return true;
+    }
+
+    if template.is_dry_run {
+        print_action_dry_run(Action::UpdateFile, &path_str);
+        return true;
+    }
+
+    match write_file(&path, &merged) {
+        Ok(_) => {
+            print_action_done(Action::UpdateFile, &path_str);
+            true
+        }
+        Err(err) => {
+            print_action_err(Action::UpdateFile, &path_str, &err.to_string());
+            false
+        }
+    }
+}
+
+fn setup_template_database(template: &Template, current_dir: &Path) {
+    let database = template.lang.prop.database;
+    let compose_path = current_dir.join("docker-compose.yml");
+    let compose = read_file(&compose_path).unwrap_or_default();
+    let env = read_file(current_dir.join(".env")).unwrap_or_default();
+    let (current_database, compose_project_name) = find_project_database(&compose, &env);
+    let is_switching = current_database != database;
+
+    for env_filename in [".env", ".env.example"] {
+        let env_path = current_dir.join(env_filename);
+        if !env_path.is_file() {
+            continue;
+        }
+
+        let content = read_file(&env_path).unwrap_or_default();
+        let updated = update_database_env(
+            &content,
+            &database.env_vars(&template.lang.prop.project_name),
+            is_switching,
+        );
+        if updated == content {
+            continue;
+        }
+
+        write_template_database_file(&env_path, &updated, template.is_dry_run);
+    }
+
+    if !is_switching || !compose_path.is_file() {
+        return;
+    }
+
+    let compose_project_name = compose_project_name.unwrap_or(&template.lang.prop.project_name);
+    let current_service = current_database.compose_service(compose_project_name);
+    let service = database.compose_service(compose_project_name);
+    let updated = if current_service.is_empty() {
+        let separator = if compose.is_empty() || compose.ends_with('\n') { "" } else { "\n" };
+        format!("{}{}{}", compose, separator, service)
+    } else if compose.contains(&current_service) {
+        compose.replacen(&current_service, &service, 1)
+    } else {
+        print_action_warn(
+            Action::UpdateFile,
+            &path_to_str(&compose_path),
+            &format!(
+                "The {} service was changed by hand, switch it to {} manually",
+                current_database.title(),
+                database.title()
+            ),
+        );
+        return;
+    };
+
+    if updated != compose {
+        write_template_database_file(&compose_path, &updated, template.is_dry_run);
+    }
+}
+
+fn find_project_database<'a>(compose: &'a str, env: &str) -> (Database, Option<&'a str>) {
+    for line in compose.lines() {
+        let Some(service) = line.strip_prefix("  ").and_then(|l| l.strip_suffix(':')) else {
+            continue;
+        };
+
+        for database in Database::ALL {
+            if let Some(name) = service.strip_suffix(&format!("-{}", database.name())) {
+                return (*database, Some(name));
+            }
+        }
+    }
+
+    let has_key = |key: &str| env.lines().any(|line| line.starts_with(&format!("{}=", key)));
+    let database = Database::ALL
+        .iter()
+        .copied()
+        .filter(|database| database.compose_service("").is_empty())
+        .find(|database| {
+            let vars = database.env_vars("");
+            !vars.is_empty() && vars.iter().all(|(key, _)| has_key(key))
+        })
+        .unwrap_or(Database::Memory);
+
+    (database, None)
+}
+
+fn update_database_env(content: &str, vars: &[(&str, String)], is_switching: bool) -> String {
+    let database_keys: Vec<&str> = Database::ALL
+        .iter()
+        .flat_map(|database| database.env_vars(""))
+        .map(|(key, _)| key)
+        .collect();
+    let mut lines: Vec<String> = Vec::new();
+    let mut written_keys: Vec<&str> = Vec::new();
+
+    for line in content.lines() {
+        let key = line.split_once('=').map(|(key, _)| key.trim());
+        let var = key.and_then(|key| vars.iter().find(|(var_key, _)| *var_key == key));
+
+        match (key, var) {
+            (Some(_), Some((key, value))) if is_switching => {
+                if !written_keys.contains(key) {
+                    lines.push(format!("{}={}", key, value));
+                    written_keys.push(key);
+                }
+            }
+            (Some(key), None) if is_switching && database_keys.contains(&key) => {}
+            (_, Some((key, _))) => {
+                lines.push(line.to_string());
+                written_keys.push(key);
+            }
+            _ => lines.push(line.to_string()),
+        }
+    }
+
+    for (key, value) in vars {
+        if !written_keys.contains(key) {
+            lines.push(format!("{}={}", key, value));
+        }
+    }
+
+    if lines.iter().map(String::as_str).eq(content.lines()) {
+        return content.to_string();
+    }
+
+    format!("{}\n", lines.join("\n"))
+}
+
+fn write_template_database_file(path: &Path, content: &str, is_dry_run: bool) {
+    let path_str = path_to_str(path);
+    if is_dry_run {
+        print_action_dry_run(Action::UpdateFile, &path_str);
+        return;
+    }
+
+    match write_file(path, content) {
+        Ok(_) => print_action_done(Action::UpdateFile, &path_str),
+        Err(err) => print_action_warn(Action::UpdateFile, &path_str, &err.to_string()),
+    }
 }
 
 fn get_template_components(
@@ -1345,7 +2589,7 @@ fn process_template(
     template: &Template,
     dir_components: &[Component],
     dir_entries: &[DirEntry],
-    current_dir: &PathBuf,
+    current_dir: &Path,
     created_target_paths: &mut Vec<PathBuf>,
 ) -> bool {
     let name_pascal_case = to_pascal_case(name);
@@ -1354,7 +2598,7 @@ fn process_template(
         let entry_components: Vec<Component> = dir_entry.path().components().collect();
         let project_entry_components: Vec<Component> =
             if entry_components.starts_with(dir_components) {
-                entry_components[dir_components.len()..].to_vec()
+                strip_variant_components(&entry_components[dir_components.len()..])
             } else {
                 Vec::new()
             };
@@ -1364,19 +2608,11 @@ fn process_template(
             return false;
         }
 
-        let project_entry_component_vec: Vec<String> = project_entry_components
-            .iter()
-            .filter_map(|c| {
-                let s = c.as_os_str().to_str().unwrap_or_default().to_string();
-                if !s.ends_with(EXTENSION_TO_REMOVE) {
-                    Some(s.replace(TEMPLATE_PREFIX_FILENAME, name))
-                } else {
-                    None
-                }
-            })
-            .collect();
-
-        let project_entry_component_str = project_entry_component_vec.join(MAIN_SEPARATOR_STR);
+        let project_entry_component_str = build_template_entry_dir(
+            &project_entry_components,
+            name,
+            &template.lang.package_dir(),
+        );
 
         let (target_filename, target_content) =
             match extract_template_content(template, dir_entry, name) {
@@ -1391,14 +2627,23 @@ fn process_template(
             .join(&target_filename);
 
         if target_path.exists() {
-            print_warn_with_info(
+            print_action_skip(
+                Action::CreateFile,
+                &path_to_str(&target_path),
                 &format!("File `{}` already exists", path_to_colored(&target_path)),
-                "Skipping.",
             );
             continue;
         }
 
-        if !create_template_file(
+        if template.is_dry_run {
+            let parent = parent_dir(&target_path);
+            if !parent.exists() && !created_target_paths.contains(&parent) {
+                print_action_dry_run(Action::CreateDir, &path_to_str(&parent));
+                created_target_paths.push(parent);
+            }
+
+            print_action_dry_run(Action::CreateFile, &path_to_str(&target_path));
+        } else if !create_template_file(
             &target_path,
             &target_content,
             name,
@@ -1413,6 +2658,7 @@ fn process_template(
                 &parent_dir(&target_path),
                 &file_stem(&target_path),
                 &template.lang,
+                template.is_dry_run,
             );
         }
     }
@@ -1420,6 +2666,24 @@ fn process_template(
     true
 }
 
+fn build_template_entry_dir(components: &[Component], name: &str, package_dir: &str) -> String {
+    let component_vec: Vec<String> = components
+        .iter()
+        .filter_map(|c| {
+            let s = c.as_os_str().to_str().unwrap_or_default().to_string();
+            if s == PACKAGE_DIR_NAME {
+                Some(package_dir.to_string())
+            } else if !s.ends_with(EXTENSION_TO_REMOVE) {
+                Some(s.replace(TEMPLATE_PREFIX_FILENAME, name))
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    component_vec.join(MAIN_SEPARATOR_STR)
+}
+
 fn extract_template_content(
     template: &Template,
     dir_entry: &DirEntry,
@@ -1429,34 +2693,33 @@ fn extract_template_content(
     let content = file.contents_utf8()?.to_string();
     let entry_filepath = dir_entry.path();
     let entry_filepath_str = entry_filepath.to_string_lossy();
+    let name_pascal_case = to_pascal_case(name);
 
     let filename = match template.part {
-        StructurePart::Common | StructurePart::Domain => {
-            if entry_filepath_str.contains(&template.part.dir_name()) {
-                let relative_path = entry_filepath
-                    .strip_prefix(template.part.dir_name())
-                    .unwrap_or(entry_filepath);
-                let relative_path_str = relative_path.to_string_lossy();
-                if relative_path_str.contains(TEMPLATE_PREFIX_FILENAME) {
-                    file_stem(relative_path).replace(TEMPLATE_PREFIX_FILENAME, name)
-                } else {
-                    return None;
-                }
-            } else {
+        StructurePart::Common | StructurePart::Domain
+            if entry_filepath_str.contains(template.part.dir_name()) =>
+        {
+            let relative_path = entry_filepath
+                .strip_prefix(template.part.dir_name())
+                .unwrap_or(entry_filepath);
+            if !path_contains_template(relative_path) {
                 return None;
             }
+
+            file_stem(relative_path)
+                .replace(TEMPLATE_PREFIX_FILENAME, name)
+                .replace(TEMPLATE_PASCAL_CASE_PREFIX_FILENAME, &name_pascal_case)
         }
-        StructurePart::Feature => {
-            if entry_filepath_str.contains(&template.part.dir_name())
-                || entry_filepath_str.contains(StructurePart::Domain.dir_name())
-            {
-                let relative_path = entry_filepath
-                    .strip_prefix(template.part.dir_name())
-                    .unwrap_or(entry_filepath);
-                file_stem(relative_path).replace(TEMPLATE_PREFIX_FILENAME, name)
-            } else {
-                return None;
-            }
+        StructurePart::Feature
+            if entry_filepath_str.contains(template.part.dir_name())
+                || entry_filepath_str.contains(StructurePart::Domain.dir_name()) =>
+        {
+            let relative_path = entry_filepath
+                .strip_prefix(template.part.dir_name())
+                .unwrap_or(entry_filepath);
+            file_stem(relative_path)
+                .replace(TEMPLATE_PREFIX_FILENAME, name)
+                .replace(TEMPLATE_PASCAL_CASE_PREFIX_FILENAME, &name_pascal_case)
         }
         _ => return None,
     };
@@ -1470,7 +2733,7 @@ fn replace_template_content(
     name: &str,
     name_pascal_case: &str,
 ) -> String {
-    content = content.replace(STRUCTURE_VERSION_TO_REPLACE, &template.version.name());
+    content = content.replace(STRUCTURE_VERSION_TO_REPLACE, template.version.name());
     content = content.replace(PROJECT_NAME_TO_REPLACE, &template.lang.prop.project_name);
     content = content.replace(MODULE_NAME_TO_REPLACE, &template.lang.prop.module_name);
     content = content.replace(LANGUAGE_NAME_TO_REPLACE, &template.lang.structure_dir_name);
@@ -1497,7 +2760,7 @@ fn create_template_file(
 
     match create_file(target_path, target_content) {
         Ok(_) => {
-            print_done(&format!("Create file `{}`", path_to_colored(target_path)));
+            print_action_done(Action::CreateFile, &path_to_str(target_path));
             created_target_paths.push(target_path.clone());
             if project_entry_component_str.contains(name) {
                 created_target_paths.push(parent);
@@ -1505,11 +2768,218 @@ fn create_template_file(
             true
         }
         Err(err) => {
-            print_warn_with_info(
-                &format!("Failed to create file `{}`", path_to_colored(target_path)),
-                &err,
-            );
+            print_action_warn(Action::CreateFile, &path_to_str(target_path), &err.to_string());
             false
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::structure::{Version, DIR};
+    use crate::sys::{create_dir, write_file};
+    use std::env::temp_dir;
+    use std::process::id;
+
+    const EXISTING_MANIFEST: &str = r#"[package]
+name = "existing"
+version = "0.1.0"
+edition = "2024"
+
+[dependencies]
+serde = "1.0.100"
+tokio = { version = "1.48.0", features = ["macros"] }
+anyhow = "1.0.98"
+
+[dev-dependencies]
+tempfile = "3"
+"#;
+
+    fn create_existing_crate(name: &str) -> PathBuf {
+        let dir = temp_dir().join(format!("uwais-{}-{}", name, id()));
+        let _ = remove_dir(&dir);
+        create_dir(dir.join("src")).unwrap();
+        write_file(dir.join("Cargo.toml"), EXISTING_MANIFEST).unwrap();
+        write_file(
+            dir.join("src").join("main.rs"),
+            "fn main() {\n    println!(\"Hello, world!\");\n}\n",
+        )
+        .unwrap();
+        write_file(dir.join(".gitignore"), "/target\n").unwrap();
+
+        dir
+    }
+
+    fn create_rust_structure() -> Structure {
+        let mut lang = Lang::new(OS::new(), "rust");
+        lang.prop.project_name = "existing".to_string();
+        lang.prop.database = Database::Postgres;
+
+        Structure::new(Version::V4, lang)
+    }
+
+    #[test]
+    fn merges_template_into_existing_crate() {
+        let dir = create_existing_crate("merge");
+        let structure = create_rust_structure();
+        let template = DIR
+            .get_file("v4/rust/__http/axum/Cargo.toml.txt")
+            .and_then(|file| file.contents_utf8())
+            .unwrap();
+        let rendered = replace_template_placeholders(template.to_string(), &structure);
+
+        let existing = read_file(dir.join("Cargo.toml")).unwrap();
+        let merged = merge_manifest(&existing, &rendered).unwrap();
+        let manifest = merged.parse::<DocumentMut>().unwrap();
+        let dependencies = &manifest["dependencies"];
+
+        assert_eq!(manifest["package"]["edition"].as_str(), Some("2024"));
+        assert_eq!(manifest_version(&dependencies["serde"]), Some("1.0.219"));
+        assert_eq!(manifest_features(&dependencies["serde"]), vec!["derive"]);
+        assert_eq!(manifest_version(&dependencies["tokio"]), Some("1.48.0"));
+        assert_eq!(
+            manifest_features(&dependencies["tokio"]),
+            vec!["macros", "full"]
+        );
+        assert_eq!(
+            manifest_features(&dependencies["sqlx"]),
+            vec!["runtime-tokio", "postgres"]
+        );
+        assert_eq!(dependencies["anyhow"].as_str(), Some("1.0.98"));
+        assert_eq!(manifest["dev-dependencies"]["tempfile"].as_str(), Some("3"));
+        assert_eq!(manifest["profile"]["release"]["lto"].as_bool(), Some(true));
+
+        let _ = remove_dir(&dir);
+    }
+
+    #[test]
+    fn replaces_scaffold_files_of_existing_crate() {
+        let dir = create_existing_crate("scaffold");
+        let structure = create_rust_structure();
+
+        assert_eq!(
+            find_replaceable_entries(&structure, &dir),
+            vec![
+                PathBuf::from("Cargo.toml"),
+                PathBuf::from("src/main.rs"),
+                PathBuf::from(".gitignore"),
+            ]
+        );
+
+        write_file(dir.join("src").join("main.rs"), "fn main() {}\n").unwrap();
+        assert_eq!(
+            find_replaceable_entries(&structure, &dir),
+            vec![PathBuf::from("Cargo.toml"), PathBuf::from(".gitignore")]
+        );
+
+        let _ = remove_dir(&dir);
+    }
+
+    #[test]
+    fn defers_canonical_commands_for_each_os() {
+        let mut lang = Lang::new(OS::new(), "python");
+        lang.prop.is_use_vendoring = true;
+        lang.prop.python_toolchain = PythonToolchain::Pip;
+        lang.set_offline(true);
+        let structure = Structure::new(Version::V4, lang);
+        let work_path = temp_dir();
+
+        let sh_commands = collect_deferred_commands(&structure, &work_path, OSKind::Linux);
+        let ps1_commands = collect_deferred_commands(&structure, &work_path, OSKind::Windows);
+
+        assert!(render_setup_sh(&sh_commands)
+            .contains("\npython -m venv venv\nvenv/bin/pip install -r requirements.txt\n"));
+        assert!(render_setup_ps1(&ps1_commands)
+            .contains("\n& venv\\Scripts\\pip.exe install -r requirements.txt\n"));
+    }
+
+    #[test]
+    fn keeps_higher_versions_of_existing_dependencies() {
+        assert!(is_version_lower("1.0.100", "1.0.219"));
+        assert!(is_version_lower("^0.7", "0.8.6"));
+        assert!(!is_version_lower("1.48.0", "1.45.1"));
+        assert!(!is_version_lower("3", "3"));
+    }
+
+    #[test]
+    fn keeps_database_files_on_dry_run() {
+        let dir = temp_dir().join(format!("uwais-database-dry-run-{}", id()));
+        let _ = remove_dir(&dir);
+        let env = "DB_HOST=localhost\nDB_PORT=5432\nDB_NAME=shop\n";
+        let compose = format!("services:\n{}", Database::Postgres.compose_service("shop"));
+        create_dir(&dir).unwrap();
+        write_file(dir.join(".env"), env).unwrap();
+        write_file(dir.join(".env.example"), env).unwrap();
+        write_file(dir.join("docker-compose.yml"), &compose).unwrap();
+
+        let mut lang = Lang::new(OS::new(), "go");
+        lang.prop.project_name = "shop".to_string();
+        lang.prop.database = Database::MySQL;
+        let mut template = Template::new(Version::V4, StructurePart::Feature, lang);
+        template.is_dry_run = true;
+
+        setup_template_database(&template, &dir);
+
+        assert_eq!(read_file(dir.join(".env")).unwrap(), env);
+        assert_eq!(read_file(dir.join(".env.example")).unwrap(), env);
+        assert_eq!(read_file(dir.join("docker-compose.yml")).unwrap(), compose);
+
+        remove_dir(&dir).unwrap();
+    }
+
+    #[test]
+    fn adds_database_dependencies_to_existing_crate() {
+        let dir = create_existing_crate("add-database");
+        let structure = create_rust_structure();
+        let template = Template::new(Version::V4, StructurePart::Feature, structure.lang);
+
+        let is_installed = install_template_database_packages(&template, &dir);
+        let manifest = read_file(dir.join("Cargo.toml"))
+            .unwrap()
+            .parse::<DocumentMut>()
+            .unwrap();
+        let dependencies = &manifest["dependencies"];
+        let _ = remove_dir(&dir);
+
+        assert!(is_installed);
+        assert_eq!(manifest_version(&dependencies["sqlx"]), Some("0.8.6"));
+        assert_eq!(
+            manifest_features(&dependencies["sqlx"]),
+            vec!["runtime-tokio", "postgres"]
+        );
+        assert_eq!(manifest_version(&dependencies["tokio"]), Some("1.48.0"));
+        assert_eq!(manifest["dev-dependencies"]["tempfile"].as_str(), Some("3"));
+    }
+
+    #[test]
+    fn lists_git_commands_on_dry_run() {
+        let dir = temp_dir().join(format!("uwais-git-dry-run-{}", id()));
+        let mut structure = create_rust_structure();
+        structure.lang.preset.is_git_init = Some(true);
+        structure.is_dry_run = true;
+
+        assert!(initialize_git_repository(&structure, &dir).is_ok());
+        assert!(!dir.exists());
+    }
+
+    #[test]
+    fn quotes_shell_metacharacters_in_setup_scripts() {
+        let commands = vec![
+            ["echo", "a>b", "(x)", "#c", "~", "!", "@y", "{z}", "a,b"]
+                .map(String::from)
+                .to_vec(),
+            ["pip", "freeze", STDOUT_REDIRECT, "requirements.txt"]
+                .map(String::from)
+                .to_vec(),
+        ];
+
+        let sh = render_setup_sh(&commands);
+        let ps1 = render_setup_ps1(&commands);
+
+        assert!(sh.contains("\necho 'a>b' '(x)' '#c' '~' '!' '@y' '{z}' 'a,b'\n"));
+        assert!(sh.contains("\npip freeze > requirements.txt\n"));
+        assert!(ps1.contains("\n& echo 'a>b' '(x)' '#c' '~' '!' '@y' '{z}' 'a,b'\n"));
+        assert!(ps1.contains("\n& pip freeze > requirements.txt\n"));
+    }
+}
@@ -1,33 +1,136 @@
-use colored::Colorize;
-use std::path::MAIN_SEPARATOR_STR;
+use colored::{ColoredString, Colorize};
+use once_cell::sync::OnceCell;
+use serde_json::{json, Value as Json};
+use std::collections::BTreeMap;
+use std::env;
+use std::io::{self, Write};
+use std::path::{Path, MAIN_SEPARATOR_STR};
 
+use crate::arg::Flag;
+use crate::command::{Spec, GENERATOR_FLAGS, GLOBAL_FLAGS};
+use crate::failure::Failure;
+use crate::structure::Part as StructurePart;
 use crate::sys::VERSION;
 
 const ARROW: &str = "=>";
 const DONE_TAG: &str = "[DONE]";
 const WARN_TAG: &str = "[WARN]";
 const ERR_TAG: &str = "[ERR]";
+const DRY_RUN_TAG: &str = "[DRY RUN]";
+
+const STATUS_INFO: &str = "info";
+const STATUS_DONE: &str = "done";
+const STATUS_DRY_RUN: &str = "dry_run";
+const STATUS_SKIPPED: &str = "skipped";
+const STATUS_WARN: &str = "warn";
+const STATUS_ERR: &str = "error";
+const STATUS_VERBOSE: &str = "verbose";
+
+const NO_COLOR_ENV: &str = "NO_COLOR";
+
+static OUTPUT: OnceCell<Output> = OnceCell::new();
+static VERBOSITY: OnceCell<Verbosity> = OnceCell::new();
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Output {
+    #[default]
+    Text,
+    Json,
+}
+
+impl Output {
+    pub const NAMES: &'static [&'static str] = &["text", "json"];
+
+    #[inline]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "text" => Some(Output::Text),
+            "json" => Some(Output::Json),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Verbosity {
+    Quiet,
+    #[default]
+    Normal,
+    Verbose,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    CreateDir,
+    CreateFile,
+    UpdateFile,
+    UpdateModFile,
+    Exec,
+}
+
+impl Action {
+    #[inline]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Action::CreateDir => "create_dir",
+            Action::CreateFile => "create_file",
+            Action::UpdateFile => "update_file",
+            Action::UpdateModFile => "update_mod_file",
+            Action::Exec => "exec",
+        }
+    }
+
+    #[inline]
+    const fn label(self) -> &'static str {
+        match self {
+            Action::CreateDir => "Create dir",
+            Action::CreateFile => "Create file",
+            Action::UpdateFile => "Update file",
+            Action::UpdateModFile => "Update mod/lib file",
+            Action::Exec => "Exec",
+        }
+    }
+
+    #[inline]
+    fn colorize(self, target: &str) -> ColoredString {
+        match self {
+            Action::Exec => target.bright_cyan(),
+            _ => target.bright_blue(),
+        }
+    }
+
+    #[inline]
+    fn text(self, target: &str) -> String {
+        format!("{} `{}`", self.label(), self.colorize(target))
+    }
+}
 
 const BASIC_COMMANDS: &[(&str, &str)] = &[
     ("version", "Get the current uwais version"),
     ("update", "Update to the latest uwais version"),
+    ("list", "List the supported languages, versions and generated files"),
+    ("completions SHELL", "Print the bash, zsh, fish or powershell completion script"),
 ];
 
 const GENERATOR_COMMANDS: &[(&str, &str, &str)] = &[
-    ("go", "[VERSION]", "Generate new Golang project"),
-    ("py", "[VERSION]", "Generate new Python project"),
-    ("ts", "[VERSION]", "Generate new TypeScript project"),
-    ("node", "[VERSION]", "Generate new NodeJS project"),
-    ("rs", "[VERSION]", "Generate new Rust project"),
-    // ("sveltekit", "[VERSION]", "Generate new SvelteKit project"),
+    ("go", "[VERSION] [DIR]", "Generate new Golang project"),
+    ("py", "[VERSION] [DIR]", "Generate new Python project"),
+    ("ts", "[VERSION] [DIR]", "Generate new TypeScript project"),
+    ("node", "[VERSION] [DIR]", "Generate new NodeJS project"),
+    ("rs", "[VERSION] [DIR]", "Generate new Rust project"),
+    ("sveltekit", "[VERSION] [DIR]", "Generate new SvelteKit project"),
+    ("java", "[VERSION] [DIR]", "Generate new Java project"),
+    ("cs", "[VERSION] [DIR]", "Generate new C# project"),
+    ("php", "[VERSION] [DIR]", "Generate new PHP project"),
 ];
 
 #[inline]
 pub fn print_help() {
     println!();
     print_welcome_banner();
-    println!("\n\n{}", "Command:");
+    println!("\n\nCommand:");
     print_commands();
+    print_generator_options();
     print_import_section();
     print_add_section();
     print_notes();
@@ -38,12 +141,10 @@ pub fn print_help() {
 #[inline]
 fn print_welcome_banner() {
     println!(
-        "{} {}{}",
-        "Welcome to",
-        format!("Uwais (v{})", VERSION).bright_white().bold(),
-        "—formerly named AyaPingPing, a project structure generator to build applications that follow Clean Architecture and \
+        "Welcome to {}—formerly named AyaPingPing, a project structure generator to build applications that follow Clean Architecture and \
         Feature-Driven Design concept in various programming languages (such as Golang, Python, Typescript, etc.). It aims to be a seamless \
         and very simple project structure while avoiding unnecessary complexity.",
+        format!("Uwais (v{})", VERSION).bright_white().bold(),
     );
 }
 
@@ -62,12 +163,22 @@ fn print_commands() {
     }
 }
 
+#[inline]
+fn print_generator_options() {
+    for flag in GENERATOR_FLAGS {
+        println!(
+            "    {}{}",
+            format!("{:<26}", format_flag(flag)).bright_purple(),
+            flag.description
+        );
+    }
+}
+
 #[inline]
 fn print_import_section() {
     println!(
-        "{:<48}{}",
-        format!("{} {}", "import".bright_cyan(), "OPTION".bright_purple()),
-        "Command to import function(s) from a source project"
+        "{:<48}Command to import function(s) from a source project",
+        format!("{} {}", "import".bright_cyan(), "OPTION".bright_purple())
     );
 
     print_option_commands("import");
@@ -76,9 +187,8 @@ fn print_import_section() {
 #[inline]
 fn print_add_section() {
     println!(
-        "{:<48}{}",
-        format!("{} {}", "add".bright_cyan(), "OPTION".bright_purple()),
-        "Command to add template(s) to the current project"
+        "{:<48}Command to add template(s) to the current project",
+        format!("{} {}", "add".bright_cyan(), "OPTION".bright_purple())
     );
 
     print_option_commands("add");
@@ -151,6 +261,49 @@ fn print_notes() {
             "-".bright_yellow(),
             "... represents multiple values separated by commas (no spaces allowed)"
         ),
+        format!(
+            "{} {}",
+            "-".bright_yellow(),
+            "Options can be placed anywhere, prompts are only shown for values that were not given"
+        ),
+        format!(
+            "{} {} {}",
+            "-".bright_yellow(),
+            "Run",
+            "uwais COMMAND --help to show the help message of a command".bright_cyan()
+        ),
+        format!(
+            "{} {} {}",
+            "-".bright_yellow(),
+            "--dry-run".bright_purple(),
+            "also works with import and add, it lists the changes without touching the disk"
+        ),
+        format!(
+            "{} {} {}",
+            "-".bright_yellow(),
+            "--quiet, --verbose and --no-color".bright_purple(),
+            "work with every command, NO_COLOR=1 also disables the colors"
+        ),
+        format!(
+            "{} {} {}",
+            "-".bright_yellow(),
+            "--output json".bright_purple(),
+            "works with every command, it prints one JSON event (action, path, status, message) per line"
+        ),
+        format!(
+            "{} {}",
+            "-".bright_yellow(),
+            "An answers FILE may define project_name, module_name, use_vendoring, python_command, python_toolchain, http_framework, database, package_manager, port, git_init, git_message and git_author, options take precedence over it"
+        ),
+        format!(
+            "{} Exit codes: {}",
+            "-".bright_yellow(),
+            Failure::ALL
+                .iter()
+                .map(|f| format!("{} ({})", f.code(), f.name()))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
         format!(
             "{} {}",
             "-".bright_yellow(),
@@ -196,13 +349,78 @@ fn print_usage_examples() {
     println!("Generate a new Golang project:\n");
     println!("{:>9} {}", "uwais".bright_cyan(), "go".bright_cyan());
 
-    println!("\nImport features from another project:\n");
+    println!("\nGenerate a new Golang project without any prompt:\n");
     println!(
         "{:>9} {} {} {} {}",
         "uwais".bright_cyan(),
+        "go".bright_cyan(),
+        "--name myproject".bright_purple(),
+        "--module github.com/user/myproject --http fiber --db postgres".bright_purple(),
+        "--yes".bright_purple()
+    );
+
+    println!("\nGenerate a new Rust project using axum and MongoDB:\n");
+    println!(
+        "{:>9} {} {}",
+        "uwais".bright_cyan(),
+        "rust".bright_cyan(),
+        "--http axum --db mongodb".bright_purple()
+    );
+
+    println!("\nGenerate a new TypeScript project managed by pnpm:\n");
+    println!(
+        "{:>9} {} {}",
+        "uwais".bright_cyan(),
+        "ts".bright_cyan(),
+        "--pm pnpm".bright_purple()
+    );
+
+    println!("\nGenerate a new Rust project and create its first Git commit:\n");
+    println!(
+        "{:>9} {} {}",
+        "uwais".bright_cyan(),
+        "rust".bright_cyan(),
+        "--git --git-message \"Start myproject\"".bright_purple()
+    );
+
+    println!("\nGenerate a new Java project with its base package:\n");
+    println!(
+        "{:>9} {} {}",
+        "uwais".bright_cyan(),
+        "java".bright_cyan(),
+        "--module com.example.myproject".bright_purple()
+    );
+
+    println!("\nGenerate a new Python project without the todo example:\n");
+    println!(
+        "{:>9} {} {}",
+        "uwais".bright_cyan(),
+        "py".bright_cyan(),
+        "--minimal".bright_purple()
+    );
+
+    println!("\nGenerate a new Golang project and defer its toolchain commands:\n");
+    println!(
+        "{:>9} {} {}",
+        "uwais".bright_cyan(),
+        "go".bright_cyan(),
+        "--offline".bright_purple()
+    );
+
+    println!("\nGenerate a new Golang project into the current directory:\n");
+    println!(
+        "{:>9} {} {}",
+        "uwais".bright_cyan(),
+        "go".bright_cyan(),
+        ".".bright_blue()
+    );
+
+    println!("\nImport features from another project:\n");
+    println!(
+        "{:>9} {} {} user,product {}",
+        "uwais".bright_cyan(),
         "import".bright_cyan(),
         "feature".bright_purple(),
-        "user,product",
         format!(
             "{}my{}project{}path",
             MAIN_SEPARATOR_STR, MAIN_SEPARATOR_STR, MAIN_SEPARATOR_STR
@@ -212,32 +430,304 @@ fn print_usage_examples() {
 
     println!("\nImport common functions from another project:\n");
     println!(
-        "{:>9} {} {} {} {}",
+        "{:>9} {} {} validator,formatter {}",
         "uwais".bright_cyan(),
         "import".bright_cyan(),
         "common".bright_purple(),
-        "validator,formatter",
         "git@github.com:username/my/project.git".bright_blue()
     );
+
+    println!("\nAdd a feature backed by MySQL to the current project:\n");
+    println!(
+        "{:>9} {} {} order {}",
+        "uwais".bright_cyan(),
+        "add".bright_cyan(),
+        "feature".bright_purple(),
+        "--db mysql".bright_purple()
+    );
+
+    println!("\nEnable shell completion for bash:\n");
+    println!(
+        "{:>16}{} {} {}{}",
+        "source <(".bright_cyan(),
+        "uwais".bright_cyan(),
+        "completions".bright_cyan(),
+        "bash".bright_purple(),
+        ")".bright_cyan()
+    );
+}
+
+pub fn print_command_help(name: &str, spec: &Spec) {
+    let args: String = spec
+        .args
+        .iter()
+        .map(|arg| {
+            if arg.is_required {
+                format!(" {}", arg.name)
+            } else {
+                format!(" [{}]", arg.name)
+            }
+        })
+        .collect();
+
+    println!();
+    println!(
+        "{} {}{} {}",
+        "Usage:".bright_green(),
+        format!("uwais {}", name).bright_cyan(),
+        args,
+        "[OPTIONS]".bright_purple()
+    );
+    println!("\n{}", spec.description);
+
+    if !spec.args.is_empty() {
+        println!("\n\n{}", "Arguments:".bright_yellow());
+
+        for arg in spec.args {
+            println!("{:<30}{}", arg.name.bright_blue(), arg.description);
+        }
+    }
+
+    println!("\n\n{}", "Options:".bright_yellow());
+
+    for flag in spec.flags.iter().chain(GLOBAL_FLAGS) {
+        println!(
+            "{}{}",
+            format!("{:<30}", format_flag(flag)).bright_purple(),
+            flag.description
+        );
+    }
+
+    println!();
+}
+
+#[inline]
+fn format_flag(flag: &Flag) -> String {
+    let names = match flag.short {
+        Some(short) => format!("{}, {}", short, flag.long),
+        None => flag.long.to_string(),
+    };
+
+    format!("{} {}", names, flag.value.unwrap_or_default())
+}
+
+pub fn print_list<W: Write>(out: &mut W, list: &Json) -> io::Result<()> {
+    writeln!(out)?;
+    writeln!(out, "{}", "Languages:".bright_yellow())?;
+
+    for lang in json_array(list, "languages") {
+        let aliases: Vec<&str> = json_array(lang, "aliases")
+            .iter()
+            .filter_map(|a| a.as_str())
+            .collect();
+
+        writeln!(
+            out,
+            "{:<30}{}",
+            lang["name"].as_str().unwrap_or_default(),
+            aliases.join(", ").bright_cyan()
+        )?;
+    }
+
+    writeln!(out, "\n\n{}", "Structure versions:".bright_yellow())?;
+
+    let default_version = list["default_version"].as_str().unwrap_or_default();
+
+    for version in json_array(list, "versions").iter().filter_map(|v| v.as_str()) {
+        if version == default_version {
+            writeln!(out, "{} {}", version, "(default)".bright_black())?;
+        } else {
+            writeln!(out, "{}", version)?;
+        }
+    }
+
+    for lang in json_array(list, "languages") {
+        for version in json_array(lang, "versions") {
+            writeln!(
+                out,
+                "\n\n{} {}",
+                lang["name"].as_str().unwrap_or_default().bright_magenta().bold(),
+                version["version"].as_str().unwrap_or_default().bright_yellow()
+            )?;
+            writeln!(out, "\n{}", "Project:".bright_green())?;
+            print_tree(out, &json_strings(&version["structure"]))?;
+
+            for part in StructurePart::ALL {
+                writeln!(
+                    out,
+                    "\n{} {}",
+                    "Template:".bright_green(),
+                    part.name().bright_purple()
+                )?;
+                print_tree(out, &json_strings(&version["templates"][part.name()]))?;
+            }
+        }
+    }
+
+    writeln!(out)?;
+
+    Ok(())
+}
+
+#[inline]
+fn json_array<'a>(value: &'a Json, key: &str) -> &'a [Json] {
+    value[key].as_array().map(Vec::as_slice).unwrap_or_default()
+}
+
+#[inline]
+fn json_strings(value: &Json) -> Vec<&str> {
+    value
+        .as_array()
+        .map(|items| items.iter().filter_map(|i| i.as_str()).collect())
+        .unwrap_or_default()
+}
+
+#[derive(Default)]
+struct TreeNode<'a> {
+    children: BTreeMap<&'a str, TreeNode<'a>>,
+}
+
+fn print_tree<W: Write>(out: &mut W, paths: &[&str]) -> io::Result<()> {
+    let mut root = TreeNode::default();
+
+    for path in paths {
+        let mut node = &mut root;
+
+        for component in Path::new(path).iter().filter_map(|c| c.to_str()) {
+            node = node.children.entry(component).or_default();
+        }
+    }
+
+    print_tree_node(out, &root, "")
+}
+
+fn print_tree_node<W: Write>(out: &mut W, node: &TreeNode, prefix: &str) -> io::Result<()> {
+    let last_index = node.children.len().saturating_sub(1);
+
+    for (i, (name, child)) in node.children.iter().enumerate() {
+        let (branch, indent) = if i == last_index {
+            ("└── ", "    ")
+        } else {
+            ("├── ", "│   ")
+        };
+
+        if child.children.is_empty() {
+            writeln!(out, "{}{}{}", prefix, branch, name)?;
+        } else {
+            writeln!(out, "{}{}{}", prefix, branch, name.bright_blue())?;
+        }
+
+        print_tree_node(out, child, &format!("{}{}", prefix, indent))?;
+    }
+
+    Ok(())
+}
+
+pub fn set_output(output: Output) {
+    if output == Output::Json {
+        disable_color();
+    }
+
+    let _ = OUTPUT.set(output);
+}
+
+#[inline]
+pub fn is_json_output() -> bool {
+    OUTPUT.get() == Some(&Output::Json)
+}
+
+#[inline]
+pub fn set_verbosity(verbosity: Verbosity) {
+    let _ = VERBOSITY.set(verbosity);
+}
+
+#[inline]
+pub fn is_quiet() -> bool {
+    VERBOSITY.get() == Some(&Verbosity::Quiet)
+}
+
+#[inline]
+pub fn is_verbose() -> bool {
+    VERBOSITY.get() == Some(&Verbosity::Verbose)
+}
+
+#[inline]
+pub fn disable_color() {
+    colored::control::set_override(false);
+}
+
+#[inline]
+pub fn is_no_color_env() -> bool {
+    env::var_os(NO_COLOR_ENV).is_some_and(|value| !value.is_empty())
+}
+
+fn print_event(action: Option<Action>, target: Option<&str>, status: &str, message: &str) {
+    let (path, message) = match (action, target) {
+        (Some(Action::Exec), Some(command)) if message.is_empty() => (None, command.to_string()),
+        (Some(Action::Exec), Some(command)) => (None, format!("{}: {}", command, message)),
+        _ => (target, message.to_string()),
+    };
+
+    println!(
+        "{}",
+        json!({
+            "action": action.map(Action::name),
+            "path": path,
+            "status": status,
+            "message": Some(message).filter(|m| !m.is_empty()),
+        })
+    )
+}
+
+#[inline]
+fn join_info(text: &str, info: &str) -> String {
+    if info.is_empty() {
+        text.to_string()
+    } else {
+        format!("{}: {}", text, info)
+    }
 }
 
 #[inline]
 pub fn print_input(text: &str) {
+    if is_json_output() {
+        eprint!("{}", text);
+        return;
+    }
+
     print!("{}", text)
 }
 
 #[inline]
 pub fn print_text(text: &str) {
+    if is_json_output() {
+        return;
+    }
+
     println!("{}", text)
 }
 
 #[inline]
 pub fn print_info(text: &str) {
+    if is_json_output() {
+        return print_event(None, None, STATUS_INFO, text);
+    }
+    if is_quiet() {
+        return;
+    }
+
     println!("{} {}...", ARROW.bright_cyan(), text)
 }
 
 #[inline]
 pub fn print_done(text: &str) {
+    if is_json_output() {
+        return print_event(None, None, STATUS_DONE, text);
+    }
+    if is_quiet() {
+        return;
+    }
+
     println!(
         "{} {}. {}",
         ARROW.bright_cyan(),
@@ -246,6 +736,29 @@ pub fn print_done(text: &str) {
     )
 }
 
+#[inline]
+pub fn print_dry_run(text: &str) {
+    if is_json_output() {
+        return print_event(None, None, STATUS_DRY_RUN, text);
+    }
+
+    println!(
+        "{} {}. {}",
+        ARROW.bright_cyan(),
+        text,
+        DRY_RUN_TAG.bright_magenta().bold()
+    )
+}
+
+#[inline]
+pub fn print_verbose(text: &str) {
+    if is_json_output() {
+        return print_event(None, None, STATUS_VERBOSE, text);
+    }
+
+    println!("{} {}", ARROW.bright_black(), text.bright_black())
+}
+
 #[inline]
 pub fn print_warn(text: &str) {
     print_warn_with_info(text, "")
@@ -253,6 +766,10 @@ pub fn print_warn(text: &str) {
 
 #[inline]
 pub fn print_warn_with_info(text: &str, info: &str) {
+    if is_json_output() {
+        return print_event(None, None, STATUS_WARN, &join_info(text, info));
+    }
+
     println!(
         "{} {}... {} !! {}.",
         ARROW.bright_yellow(),
@@ -269,6 +786,10 @@ pub fn print_err(text: &str) {
 
 #[inline]
 pub fn print_err_with_info(text: &str, info: &str) {
+    if is_json_output() {
+        return print_event(None, None, STATUS_ERR, &join_info(text, info));
+    }
+
     println!(
         "{} {}... {} !! {}.",
         ARROW.bright_red(),
@@ -277,3 +798,57 @@ pub fn print_err_with_info(text: &str, info: &str) {
         info.red().bold()
     )
 }
+
+#[inline]
+pub fn print_action_info(action: Action, target: &str) {
+    if is_json_output() {
+        return print_event(Some(action), Some(target), STATUS_INFO, "");
+    }
+
+    print_info(&action.text(target))
+}
+
+#[inline]
+pub fn print_action_done(action: Action, target: &str) {
+    if is_json_output() {
+        return print_event(Some(action), Some(target), STATUS_DONE, "");
+    }
+
+    print_done(&action.text(target))
+}
+
+#[inline]
+pub fn print_action_dry_run(action: Action, target: &str) {
+    if is_json_output() {
+        return print_event(Some(action), Some(target), STATUS_DRY_RUN, "");
+    }
+
+    print_dry_run(&action.text(target))
+}
+
+#[inline]
+pub fn print_action_skip(action: Action, target: &str, reason: &str) {
+    if is_json_output() {
+        return print_event(Some(action), Some(target), STATUS_SKIPPED, reason);
+    }
+
+    print_warn_with_info(reason, "Skipping")
+}
+
+#[inline]
+pub fn print_action_warn(action: Action, target: &str, info: &str) {
+    if is_json_output() {
+        return print_event(Some(action), Some(target), STATUS_WARN, info);
+    }
+
+    print_warn_with_info(&action.text(target), info)
+}
+
+#[inline]
+pub fn print_action_err(action: Action, target: &str, info: &str) {
+    if is_json_output() {
+        return print_event(Some(action), Some(target), STATUS_ERR, info);
+    }
+
+    print_err_with_info(&action.text(target), info)
+}
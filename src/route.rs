@@ -0,0 +1,272 @@
+use std::path::Path;
+
+use crate::sys::{filename, ls, read_file};
+
+const METHODS: &[&str] = &["GET", "POST", "PUT", "PATCH", "DELETE"];
+const QUOTES: &[char] = &['"', '\'', '`'];
+const SVELTEKIT_SERVER_FILENAME: &str = "+server.ts";
+const SVELTEKIT_EXPORT_PREFIXES: &[&str] = &[
+    "export const ",
+    "export async function ",
+    "export function ",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Route {
+    pub method: Option<&'static str>,
+    pub path: String,
+}
+
+pub fn find_routes<P: AsRef<Path>>(path: P) -> Vec<Route> {
+    let path = path.as_ref();
+    if path.is_dir() {
+        let mut routes = Vec::new();
+        find_server_routes(path, "", &mut routes);
+        routes.sort_by(|a, b| a.path.cmp(&b.path));
+
+        return routes;
+    }
+
+    read_file(path)
+        .map(|content| parse_routes(&content))
+        .unwrap_or_default()
+}
+
+pub fn find_port<P: AsRef<Path>>(compose_path: P) -> Option<u16> {
+    let content = read_file(compose_path).ok()?;
+    let mut lines = content.lines().map(|line| line.trim());
+    lines.find(|line| *line == "ports:")?;
+
+    let mapping = lines.next()?.strip_prefix('-')?.trim().trim_matches(QUOTES);
+    let (host_port, _) = mapping.split_once(':')?;
+
+    host_port.parse().ok()
+}
+
+fn find_server_routes(dir: &Path, route_path: &str, routes: &mut Vec<Route>) {
+    for entry in ls(dir) {
+        let name = filename(&entry);
+        if entry.is_dir() {
+            find_server_routes(&entry, &format!("{}/{}", route_path, name), routes);
+        } else if name == SVELTEKIT_SERVER_FILENAME {
+            let Ok(content) = read_file(&entry) else {
+                continue;
+            };
+
+            for method in parse_exported_methods(&content) {
+                routes.push(Route {
+                    method: Some(method),
+                    path: if route_path.is_empty() {
+                        "/".to_string()
+                    } else {
+                        route_path.to_string()
+                    },
+                });
+            }
+        }
+    }
+}
+
+fn parse_exported_methods(content: &str) -> Vec<&'static str> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let declaration = SVELTEKIT_EXPORT_PREFIXES
+                .iter()
+                .find_map(|prefix| line.trim_start().strip_prefix(prefix))?;
+            let name: String = declaration
+                .chars()
+                .take_while(|c| c.is_ascii_alphanumeric() || *c == '_')
+                .collect();
+
+            METHODS.iter().copied().find(|method| *method == name)
+        })
+        .collect()
+}
+
+fn parse_routes(content: &str) -> Vec<Route> {
+    let paths = find_route_literals(content);
+    let methods = find_method_tokens(content);
+
+    let nearest_path_indexes: Vec<Option<usize>> = methods
+        .iter()
+        .map(|(position, _)| nearest_index(&paths, *position))
+        .collect();
+
+    paths
+        .iter()
+        .enumerate()
+        .map(|(index, (position, path))| {
+            let method = methods
+                .iter()
+                .zip(&nearest_path_indexes)
+                .filter(|(_, path_index)| **path_index == Some(index))
+                .min_by_key(|((method_position, _), _)| method_position.abs_diff(*position))
+                .map(|((_, method), _)| *method);
+
+            Route {
+                method,
+                path: path.to_string(),
+            }
+        })
+        .collect()
+}
+
+fn nearest_index<T>(items: &[(usize, T)], position: usize) -> Option<usize> {
+    items
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, (item_position, _))| item_position.abs_diff(position))
+        .map(|(index, _)| index)
+}
+
+fn find_route_literals(content: &str) -> Vec<(usize, &str)> {
+    let mut literals = Vec::new();
+
+    for (line_start, line) in line_offsets(content) {
+        let mut rest = line;
+        let mut offset = line_start;
+        while let Some(start) = rest.find(QUOTES) {
+            let quote = rest[start..].chars().next().unwrap_or_default();
+            let Some(len) = rest[start + 1..].find(quote) else {
+                break;
+            };
+
+            let literal = &rest[start + 1..start + 1 + len];
+            if is_route_path(literal) {
+                literals.push((offset + start, literal));
+            }
+
+            let end = start + len + 2;
+            rest = &rest[end..];
+            offset += end;
+        }
+    }
+
+    literals
+}
+
+fn line_offsets(content: &str) -> impl Iterator<Item = (usize, &str)> {
+    content.split_inclusive('\n').scan(0, |offset, line| {
+        let start = *offset;
+        *offset += line.len();
+
+        Some((start, line))
+    })
+}
+
+fn is_route_path(literal: &str) -> bool {
+    let mut chars = literal.chars();
+
+    chars.next() == Some('/')
+        && chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && literal
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "/-_:{}".contains(c))
+}
+
+fn find_method_tokens(content: &str) -> Vec<(usize, &'static str)> {
+    let mut tokens = Vec::new();
+    let mut start = None;
+
+    for (position, c) in content.char_indices().chain([(content.len(), ' ')]) {
+        if c.is_ascii_alphanumeric() || c == '_' {
+            start.get_or_insert(position);
+            continue;
+        }
+
+        if let Some(token_start) = start.take() {
+            if let Some(method) = to_method(&content[token_start..position]) {
+                tokens.push((token_start, method));
+            }
+        }
+    }
+
+    tokens
+}
+
+fn to_method(token: &str) -> Option<&'static str> {
+    METHODS.iter().copied().find(|method| {
+        if token.eq_ignore_ascii_case(method) {
+            return true;
+        }
+
+        let capitalized = format!("{}{}", &method[..1], method[1..].to_lowercase());
+        token
+            .strip_suffix(&capitalized)
+            .is_some_and(|prefix| prefix.ends_with(|c: char| c.is_ascii_lowercase()))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn route(method: Option<&'static str>, path: &str) -> Route {
+        Route {
+            method,
+            path: path.to_string(),
+        }
+    }
+
+    #[test]
+    fn parses_routes_declared_after_the_method() {
+        let content = r#"
+app.MapGet("/todos", todoHttpServiceV1.GetAll);
+app.MapPost("/todo/add", todoHttpServiceV1.Add);
+"#;
+
+        assert_eq!(
+            parse_routes(content),
+            vec![
+                route(Some("GET"), "/todos"),
+                route(Some("POST"), "/todo/add")
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_routes_declared_before_the_method() {
+        let content = r#"
+        .route(
+            "/todos",
+            web::get().to(features::todo::http_service_actixweb_v1::get_all),
+        )
+        .route(
+            "/todo/remove",
+            web::post().to(features::todo::http_service_actixweb_v1::remove),
+        )
+"#;
+
+        assert_eq!(
+            parse_routes(content),
+            vec![
+                route(Some("GET"), "/todos"),
+                route(Some("POST"), "/todo/remove")
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_routes_without_methods() {
+        let content = r#"
+require __DIR__ . '/vendor/autoload.php';
+$path = parse_url($_SERVER['REQUEST_URI'] ?? '/', PHP_URL_PATH);
+match ($path) {
+    '/todos' => $todoHttpServiceV1->getAll(),
+};
+"#;
+
+        assert_eq!(parse_routes(content), vec![route(None, "/todos")]);
+    }
+
+    #[test]
+    fn parses_sveltekit_exported_methods() {
+        let content = r#"
+export const GET: RequestHandler = async () => {};
+export async function POST() {}
+"#;
+
+        assert_eq!(parse_exported_methods(content), vec!["GET", "POST"]);
+    }
+}
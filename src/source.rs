@@ -3,6 +3,7 @@ use std::path::PathBuf;
 use std::thread;
 use std::time::Duration;
 
+use crate::error::{Context, Error};
 use crate::git::{git_download, is_git_common_url, is_git_ssh_url};
 use crate::http::{download_file, fetch_json};
 use crate::os::{Kind as OSKind, OS};
@@ -117,28 +118,27 @@ impl Source {
         self.kind.requires_network()
     }
 
-    pub fn provide_dir(&mut self) -> Result<PathBuf, String> {
+    pub fn provide_dir(&mut self) -> Result<PathBuf, Error> {
         match self.kind {
             Kind::LocalPath => self.provide_local(),
             Kind::GitUrl | Kind::GitSSH => self.provide_git(),
             Kind::LatestAppRelease => self.provide_latest_release_dir(),
-            _ => Err(format!("Invalid source type: {}", self.kind.name())),
+            _ => Err(Error::InvalidInput(format!(
+                "Invalid source type: {}",
+                self.kind.name()
+            ))),
         }
     }
 
-    fn provide_local(&mut self) -> Result<PathBuf, String> {
+    fn provide_local(&mut self) -> Result<PathBuf, Error> {
         let local_path = PathBuf::from(&self.url);
         if !local_path.exists() {
-            return Err(format!(
-                "Local source does not exist: {}",
-                local_path.display()
-            ));
+            return Err(Error::NotFound(local_path).context("Local source does not exist"));
         }
         if !local_path.is_dir() {
-            return Err(format!(
-                "Local source must be a directory: {}",
-                local_path.display()
-            ));
+            return Err(
+                Error::NotADirectory(local_path).context("Local source must be a directory")
+            );
         }
 
         self.source_dir = local_path.clone();
@@ -146,14 +146,15 @@ impl Source {
         Ok(local_path)
     }
 
-    fn provide_git(&mut self) -> Result<PathBuf, String> {
+    fn provide_git(&mut self) -> Result<PathBuf, Error> {
         let tmp_dir = self.create_temp_dir("source-git");
 
         match self.git_download_with_retry(&tmp_dir) {
             Ok(_) => {
                 if !tmp_dir.is_dir() {
                     self.cleanup_temp_dir(&tmp_dir);
-                    return Err("Git source was not downloaded successfully".to_string());
+                    return Err(Error::NotFound(tmp_dir)
+                        .context("Git source was not downloaded successfully"));
                 }
 
                 self.source_dir = tmp_dir.clone();
@@ -162,38 +163,35 @@ impl Source {
             }
             Err(err) => {
                 self.cleanup_temp_dir(&tmp_dir);
-                Err(format!("Failed to download Git source: {}", err))
+                Err(err.context("Failed to download Git source"))
             }
         }
     }
 
-    fn git_download_with_retry(&self, output_dir: &PathBuf) -> Result<(), String> {
-        let mut last_error = String::new();
+    fn git_download_with_retry(&self, output_dir: &PathBuf) -> Result<(), Error> {
+        let mut attempt = 1;
 
-        for attempt in 1..=MAX_RETRIES {
+        loop {
             match git_download(&self.url, output_dir) {
                 Ok(_) => return Ok(()),
-                Err(e) => {
-                    last_error = e;
-                    if attempt < MAX_RETRIES {
-                        let delay = RETRY_DELAY_MS * 2u64.pow((attempt - 1) as u32);
-                        thread::sleep(Duration::from_millis(delay));
-                    }
+                Err(err) if !err.is_retryable() => return Err(err),
+                Err(err) if attempt >= MAX_RETRIES => {
+                    return Err(err.context(format!("Failed after {} attempts", MAX_RETRIES)))
+                }
+                Err(_) => {
+                    let delay = RETRY_DELAY_MS * 2u64.pow((attempt - 1) as u32);
+                    thread::sleep(Duration::from_millis(delay));
+                    attempt += 1;
                 }
             }
         }
-
-        Err(format!(
-            "Failed after {} attempts: {}",
-            MAX_RETRIES, last_error
-        ))
     }
 
-    fn provide_latest_release_dir(&mut self) -> Result<PathBuf, String> {
-        let release: LatestAppReleaseDTO = fetch_json(&self.url)
-            .map_err(|err| format!("Failed to fetch release info: {}", err))?;
+    fn provide_latest_release_dir(&mut self) -> Result<PathBuf, Error> {
+        let release: LatestAppReleaseDTO =
+            fetch_json(&self.url).context("Failed to fetch release info")?;
         if release.assets.is_empty() {
-            return Err("No release assets were found".to_string());
+            return Err(Error::Message("No release assets were found".to_string()));
         }
 
         let tmp_dir = self.create_temp_dir("source-latest-app-release");
@@ -202,7 +200,9 @@ impl Source {
 
         if is_dir_empty(&tmp_dir) {
             self.cleanup_temp_dir(&tmp_dir);
-            return Err("No matching release asset found for the current OS".to_string());
+            return Err(Error::Message(
+                "No matching release asset found for the current OS".to_string(),
+            ));
         }
 
         self.source_dir = tmp_dir.clone();
@@ -214,7 +214,7 @@ impl Source {
         &mut self,
         assets: &[LatestAppReleaseDTOAsset],
         output_dir: &PathBuf,
-    ) -> Result<(), String> {
+    ) -> Result<(), Error> {
         for asset in assets {
             if !self.matches_current_os(&asset.name) {
                 continue;
@@ -224,26 +224,27 @@ impl Source {
 
             download_file(&asset.browser_download_url, &output_path).map_err(|err| {
                 self.cleanup_temp_dir(output_dir);
-                format!("Failed to download release asset: {}", err)
+                err.context("Failed to download release asset")
             })?;
 
             if !output_path.is_file() {
                 self.cleanup_temp_dir(output_dir);
-                return Err(format!(
-                    "Downloaded asset not found: {}",
-                    output_path.display()
-                ));
+                return Err(Error::NotFound(output_path).context("Downloaded asset not found"));
             }
 
             return Ok(());
         }
 
-        Err("No matching asset found for the current OS".to_string())
+        Err(Error::Message(
+            "No matching asset found for the current OS".to_string(),
+        ))
     }
 
-    pub fn provide_latest_app_release(&mut self) -> Result<PathBuf, String> {
+    pub fn provide_latest_app_release(&mut self) -> Result<PathBuf, Error> {
         if self.kind != Kind::LatestAppRelease {
-            return Err("Invalid source type for latest app release".to_string());
+            return Err(Error::InvalidInput(
+                "Invalid source type for latest app release".to_string(),
+            ));
         }
 
         let tmp_dir = self.provide_dir()?;
@@ -255,28 +256,26 @@ impl Source {
     }
 
     #[inline]
-    fn find_matching_archive(&self, dir: &PathBuf) -> Result<PathBuf, String> {
+    fn find_matching_archive(&self, dir: &PathBuf) -> Result<PathBuf, Error> {
         ls(dir)
             .into_iter()
             .find(|entry| entry.is_file() && self.matches_current_os(&filename(entry)))
-            .ok_or_else(|| "No matching archive found for the current OS".to_string())
+            .ok_or_else(|| {
+                Error::Message("No matching archive found for the current OS".to_string())
+            })
     }
 
     fn extract_and_locate_binary(
         &self,
         tmp_dir: &PathBuf,
         archive_path: &PathBuf,
-    ) -> Result<PathBuf, String> {
-        extract_archive(archive_path, tmp_dir)
-            .map_err(|e| format!("Failed to extract archive: {}", e))?;
+    ) -> Result<PathBuf, Error> {
+        extract_archive(archive_path, tmp_dir)?;
 
         let binary_path = tmp_dir.join(&self.os.app_name);
 
         if !binary_path.is_file() {
-            return Err(format!(
-                "Binary not found after extraction: {}",
-                binary_path.display()
-            ));
+            return Err(Error::NotFound(binary_path).context("Binary not found after extraction"));
         }
 
         Ok(binary_path)
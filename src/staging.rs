@@ -0,0 +1,365 @@
+use std::fs::{read_dir, read_to_string, remove_dir as remove_empty_dir, rename, symlink_metadata};
+use std::path::{Path, PathBuf};
+
+use crate::error::{Context, Error};
+use crate::sys::{create_dir, cwd, filename, ls, parent_dir, remove_dir, write_file};
+use crate::time::unix_timestamp;
+
+const STAGING_DIR_PREFIX: &str = ".uwais-tmp-staging";
+const BACKUP_DIR_NAME: &str = ".backup";
+
+#[derive(Debug)]
+pub struct Staging {
+    root: PathBuf,
+    dir: PathBuf,
+    target: PathBuf,
+    overwrites: Vec<PathBuf>,
+    moved: Vec<(PathBuf, PathBuf)>,
+    created_dirs: Vec<PathBuf>,
+    is_kept: bool,
+    is_committed: bool,
+}
+
+impl Staging {
+    pub fn new<P: AsRef<Path>>(target: P, is_kept: bool) -> Result<Self, Error> {
+        let target = absolute_path(target.as_ref());
+        let name = filename(&target);
+        if name.is_empty() {
+            return Err(Error::InvalidInput(format!(
+                "Cannot stage a project into `{}`",
+                target.display()
+            )));
+        }
+
+        let root_name = format!("{}-{}", STAGING_DIR_PREFIX, unix_timestamp());
+        let parent = parent_dir(&target);
+        let created_dirs = find_missing_dirs(&parent);
+        let mut root = parent.join(&root_name);
+
+        if let Err(err) = create_dir(&root) {
+            if !target.is_dir() {
+                return Err(err.context("Failed to create the staging directory"));
+            }
+
+            root = target.join(&root_name);
+            create_dir(&root).context("Failed to create the staging directory")?;
+        }
+
+        let dir = root.join(name);
+        create_dir(&dir).context("Failed to create the staging directory")?;
+
+        Ok(Self {
+            dir: dir.canonicalize().unwrap_or(dir),
+            root,
+            target,
+            overwrites: Vec::new(),
+            moved: Vec::new(),
+            created_dirs,
+            is_kept,
+            is_committed: false,
+        })
+    }
+
+    #[inline]
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    #[inline]
+    pub fn is_kept(&self) -> bool {
+        self.is_kept
+    }
+
+    #[inline]
+    pub fn overwrite<P: AsRef<Path>>(&mut self, relative_path: P) {
+        self.overwrites.push(relative_path.as_ref().to_path_buf());
+    }
+
+    pub fn relocate_references<P: AsRef<Path>>(&self, relative_dir: P) -> Result<(), Error> {
+        let from = self.dir.to_string_lossy();
+        let to = self.target.to_string_lossy();
+
+        replace_in_text_files(&self.dir.join(relative_dir), &from, &to)
+    }
+
+    pub fn commit(&mut self) -> Result<(), Error> {
+        if !self.target.exists() {
+            create_dir(parent_dir(&self.target))?;
+            rename(&self.dir, &self.target).map_err(|err| {
+                Error::io(
+                    format!("Failed to move the project into {}", self.target.display()),
+                    err,
+                )
+            })?;
+            self.moved.push((self.dir.clone(), self.target.clone()));
+        } else {
+            let mut merge = Merge {
+                overwrites: &self.overwrites,
+                backup_dir: self.root.join(BACKUP_DIR_NAME),
+                moved: &mut self.moved,
+            };
+
+            if let Err(err) = merge.merge_dir(&self.dir, &self.target, Path::new("")) {
+                self.undo_moves();
+
+                return Err(err.context(format!(
+                    "Failed to move the project into {}",
+                    self.target.display()
+                )));
+            }
+        }
+
+        self.is_committed = true;
+
+        Ok(())
+    }
+
+    fn undo_moves(&mut self) {
+        for (from, to) in self.moved.drain(..).rev() {
+            let _ = rename(to, from);
+        }
+    }
+}
+
+impl Drop for Staging {
+    fn drop(&mut self) {
+        if (self.is_committed || !self.is_kept) && self.root.exists() {
+            let _ = remove_dir(&self.root);
+        }
+
+        if !self.is_committed && !self.is_kept {
+            for dir in &self.created_dirs {
+                let _ = remove_empty_dir(dir);
+            }
+        }
+    }
+}
+
+struct Merge<'a> {
+    overwrites: &'a [PathBuf],
+    backup_dir: PathBuf,
+    moved: &'a mut Vec<(PathBuf, PathBuf)>,
+}
+
+impl Merge<'_> {
+    fn merge_dir(&mut self, src: &Path, dst: &Path, relative_dir: &Path) -> Result<(), Error> {
+        let entries = read_dir(src)
+            .map_err(|err| Error::io(format!("Failed to read {}", src.display()), err))?;
+
+        for entry in entries.flatten() {
+            let from = entry.path();
+            let to = dst.join(entry.file_name());
+            let relative_path = relative_dir.join(entry.file_name());
+
+            if from.is_dir() && to.is_dir() {
+                self.merge_dir(&from, &to, &relative_path)?;
+                continue;
+            }
+
+            if to.exists() {
+                if !to.is_file() || !self.overwrites.contains(&relative_path) {
+                    return Err(Error::AlreadyExists(to));
+                }
+
+                let backup = self.backup_dir.join(&relative_path);
+                create_dir(parent_dir(&backup))?;
+                self.rename(&to, &backup)?;
+            }
+
+            self.rename(&from, &to)?;
+        }
+
+        Ok(())
+    }
+
+    fn rename(&mut self, from: &Path, to: &Path) -> Result<(), Error> {
+        rename(from, to).map_err(|err| {
+            Error::io(
+                format!("Failed to move {} to {}", from.display(), to.display()),
+                err,
+            )
+        })?;
+
+        self.moved.push((from.to_path_buf(), to.to_path_buf()));
+
+        Ok(())
+    }
+}
+
+fn replace_in_text_files(dir: &Path, from: &str, to: &str) -> Result<(), Error> {
+    for path in ls(dir) {
+        let Ok(metadata) = symlink_metadata(&path) else {
+            continue;
+        };
+
+        if metadata.is_dir() {
+            replace_in_text_files(&path, from, to)?;
+        } else if metadata.is_file() {
+            let Ok(content) = read_to_string(&path) else {
+                continue;
+            };
+
+            if content.contains(from) {
+                write_file(&path, &content.replace(from, to))?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn find_missing_dirs(dir: &Path) -> Vec<PathBuf> {
+    dir.ancestors()
+        .take_while(|ancestor| !ancestor.exists())
+        .map(Path::to_path_buf)
+        .collect()
+}
+
+#[inline]
+fn absolute_path(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| cwd().join(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sys::{read_file, write_file};
+    use std::env::temp_dir;
+    use std::process::id;
+
+    fn create_test_dir(name: &str) -> PathBuf {
+        let dir = temp_dir().join(format!("uwais-staging-{}-{}", name, id()));
+        let _ = remove_dir(&dir);
+        create_dir(&dir).unwrap();
+
+        dir.canonicalize().unwrap()
+    }
+
+    fn create_existing_project(name: &str) -> PathBuf {
+        let target = create_test_dir(name);
+        write_file(target.join("Cargo.toml"), "[package]").unwrap();
+        write_file(target.join("README.md"), "# Existing").unwrap();
+
+        target
+    }
+
+    fn list_names(dir: &Path) -> Vec<String> {
+        let mut names: Vec<String> = ls(dir).iter().map(filename).collect();
+        names.sort();
+
+        names
+    }
+
+    #[test]
+    fn restores_target_when_merge_conflicts() {
+        let target = create_existing_project("conflict");
+
+        let mut staging = Staging::new(&target, false).unwrap();
+        staging.overwrite("Cargo.toml");
+        write_file(staging.dir().join("Cargo.toml"), "[workspace]").unwrap();
+        write_file(staging.dir().join("README.md"), "# Generated").unwrap();
+        create_dir(staging.dir().join("src")).unwrap();
+        write_file(staging.dir().join("src").join("main.rs"), "fn main() {}").unwrap();
+
+        let err = staging.commit().unwrap_err();
+        assert!(err.is_conflict());
+        drop(staging);
+
+        assert_eq!(list_names(&target), vec!["Cargo.toml", "README.md"]);
+        assert_eq!(read_file(target.join("Cargo.toml")).unwrap(), "[package]");
+        assert_eq!(read_file(target.join("README.md")).unwrap(), "# Existing");
+
+        remove_dir(&target).unwrap();
+    }
+
+    #[test]
+    fn stages_next_to_an_existing_target() {
+        let dir = create_test_dir("sibling");
+        let target = dir.join("project");
+        create_dir(&target).unwrap();
+
+        let staging = Staging::new(&target, false).unwrap();
+        assert!(staging.dir().starts_with(&dir));
+        assert!(!staging.dir().starts_with(&target));
+        drop(staging);
+
+        assert_eq!(list_names(&dir), vec!["project"]);
+
+        remove_dir(&dir).unwrap();
+    }
+
+    #[test]
+    fn removes_created_parents_when_not_committed() {
+        let dir = create_test_dir("missing-parents");
+        let target = dir.join("a").join("b").join("project");
+
+        let staging = Staging::new(&target, false).unwrap();
+        assert!(dir.join("a").join("b").is_dir());
+        drop(staging);
+
+        assert!(ls(&dir).is_empty());
+
+        let mut staging = Staging::new(&target, false).unwrap();
+        write_file(staging.dir().join("main.go"), "package main").unwrap();
+        staging.commit().unwrap();
+        drop(staging);
+
+        assert_eq!(list_names(&dir.join("a").join("b")), vec!["project"]);
+
+        remove_dir(&dir).unwrap();
+    }
+
+    #[test]
+    fn relocates_references_to_the_staging_dir() {
+        let dir = create_test_dir("relocate");
+        let target = dir.join("project");
+
+        let mut staging = Staging::new(&target, false).unwrap();
+        let activate = Path::new("venv").join("bin").join("activate");
+        create_dir(staging.dir().join("venv").join("bin")).unwrap();
+        write_file(
+            staging.dir().join(&activate),
+            &format!("VIRTUAL_ENV={}", staging.dir().join("venv").display()),
+        )
+        .unwrap();
+
+        staging.relocate_references("venv").unwrap();
+        staging.commit().unwrap();
+        drop(staging);
+
+        assert_eq!(
+            read_file(target.join(&activate)).unwrap(),
+            format!("VIRTUAL_ENV={}", target.join("venv").display())
+        );
+
+        remove_dir(&dir).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn commits_into_existing_dir_under_read_only_parent() {
+        use std::fs::{set_permissions, Permissions};
+        use std::os::unix::fs::PermissionsExt;
+
+        let parent = create_test_dir("read-only-parent");
+        let target = parent.join("project");
+        create_dir(&target).unwrap();
+        write_file(target.join("LICENSE"), "MIT").unwrap();
+        set_permissions(&parent, Permissions::from_mode(0o555)).unwrap();
+
+        let result = Staging::new(&target, false).and_then(|mut staging| {
+            write_file(staging.dir().join("main.go"), "package main")?;
+            staging.commit()
+        });
+
+        set_permissions(&parent, Permissions::from_mode(0o755)).unwrap();
+        result.unwrap();
+        assert_eq!(read_file(target.join("LICENSE")).unwrap(), "MIT");
+        assert_eq!(read_file(target.join("main.go")).unwrap(), "package main");
+        assert!(!ls(&target)
+            .iter()
+            .any(|path| filename(path).starts_with(STAGING_DIR_PREFIX)));
+
+        remove_dir(&parent).unwrap();
+    }
+}
@@ -46,3 +46,42 @@ pub fn to_pascal_case(text: &str) -> String {
 
     pascal
 }
+
+pub fn closest_match<'a, I>(text: &str, candidates: I) -> Option<&'a str>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let max_distance = (text.chars().count() / 3).max(1);
+
+    candidates
+        .into_iter()
+        .map(|candidate| (edit_distance(text, candidate), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b_chars: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b_chars.len()).collect();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut previous = row[0];
+
+        row[0] = i + 1;
+
+        for (j, b_char) in b_chars.iter().enumerate() {
+            let current = row[j + 1];
+
+            row[j + 1] = if a_char == *b_char {
+                previous
+            } else {
+                1 + previous.min(row[j]).min(current)
+            };
+
+            previous = current;
+        }
+    }
+
+    row[b_chars.len()]
+}
@@ -3,23 +3,48 @@ use serde_json::Value as Json;
 use std::collections::HashSet;
 use std::fs::File;
 use std::io::BufReader;
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 
 use crate::lang::Lang;
 
 pub static DIR: Dir = include_dir!("$CARGO_MANIFEST_DIR/structure");
 pub const EXTENSION_TO_REMOVE: &str = ".txt";
 pub const TEMPLATE_PREFIX_FILENAME: &str = "__template";
+pub const TEMPLATE_PASCAL_CASE_PREFIX_FILENAME: &str = "__Template";
+pub const PACKAGE_DIR_NAME: &str = "__package";
+pub const HTTP_VARIANT_DIR_NAME: &str = "__http";
+pub const DB_VARIANT_DIR_NAME: &str = "__db";
+pub const TOOLCHAIN_VARIANT_DIR_NAME: &str = "__tool";
+pub const MINIMAL_OVERLAY_DIR_NAME: &str = "__minimal";
+pub const INFRA_DIR_NAME: &str = "infra";
+pub const SETUP_SCRIPT_SH_FILENAME: &str = "setup.sh";
+pub const SETUP_SCRIPT_PS1_FILENAME: &str = "setup.ps1";
 pub const STRUCTURE_VERSION_TO_REPLACE: &str = "{{STRUCTURE_VERSION}}";
 pub const PROJECT_NAME_TO_REPLACE: &str = "{{PROJECT_NAME}}";
 pub const MODULE_NAME_TO_REPLACE: &str = "{{MODULE_NAME}}";
+pub const PORT_TO_REPLACE: &str = "{{PORT}}";
+pub const MODULE_NAME_ESCAPED_TO_REPLACE: &str = "{{MODULE_NAME_ESCAPED}}";
 pub const LANGUAGE_NAME_TO_REPLACE: &str = "{{LANGUAGE_NAME}}";
 pub const LANGUAGE_EXTENSION_TO_REPLACE: &str = "{{LANGUAGE_EXTENSION}}";
 pub const VENDORING_SCRIPT_TO_REPLACE: &str = "{{VENDORING_SCRIPT}}";
 pub const TEMPLATE_NAME_TO_REPLACE: &str = "{{TEMPLATE_NAME}}";
 pub const TEMPLATE_NAME_PASCAL_CASE_TO_REPLACE: &str = "{{TEMPLATE_NAME_PASCAL_CASE}}";
+pub const DATABASE_NAME_TO_REPLACE: &str = "{{DATABASE_NAME}}";
+pub const DATABASE_NAME_PASCAL_CASE_TO_REPLACE: &str = "{{DATABASE_NAME_PASCAL_CASE}}";
+pub const DATABASE_ENV_TO_REPLACE: &str = "{{DATABASE_ENV}}";
+pub const DATABASE_SERVICE_TO_REPLACE: &str = "{{DATABASE_SERVICE}}";
+pub const DATABASE_PACKAGES_TO_REPLACE: &str = "{{DATABASE_PACKAGES}}";
+pub const DATABASE_DEPENDENCIES_TO_REPLACE: &str = "{{DATABASE_DEPENDENCIES}}";
+pub const PACKAGE_MANAGER_TO_REPLACE: &str = "{{PACKAGE_MANAGER}}";
+pub const PACKAGE_MANAGER_SETUP_TO_REPLACE: &str = "{{PACKAGE_MANAGER_SETUP}}";
 
 const DEPENDENCY_FILENAME: &str = "dependency.json";
+const VARIANT_DIR_NAMES: &[&str] = &[
+    HTTP_VARIANT_DIR_NAME,
+    DB_VARIANT_DIR_NAME,
+    TOOLCHAIN_VARIANT_DIR_NAME,
+];
+const EXAMPLE_FEATURE_NAMES: &[&str] = &["todo", "todos"];
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Version {
@@ -28,6 +53,13 @@ pub enum Version {
 }
 
 impl Version {
+    pub const ALL: &'static [Version] = &[Version::V4];
+
+    #[inline]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|v| v.name() == name)
+    }
+
     #[inline]
     pub const fn is_valid(self) -> bool {
         matches!(self, Version::V4)
@@ -70,11 +102,28 @@ pub enum Part {
 }
 
 impl Part {
+    pub const ALL: &'static [Part] = &[Part::Common, Part::Domain, Part::Feature];
+
+    #[inline]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Some(Self::from(name)).filter(|p| p.is_valid())
+    }
+
     #[inline]
     pub const fn is_valid(self) -> bool {
         !matches!(self, Part::Unknown)
     }
 
+    #[inline]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Part::Common => "common",
+            Part::Domain => "domain",
+            Part::Feature => "feature",
+            Part::Unknown => "unknown",
+        }
+    }
+
     #[inline]
     pub const fn dir_name(self) -> &'static str {
         match self {
@@ -120,11 +169,17 @@ impl Default for IncludedDir {
 
 impl IncludedDir {
     pub fn new(lang: &Lang, version: Version) -> Self {
-        let path = Path::new(version.dir_name()).join(&lang.structure_dir_name);
+        Self::find(lang, version).unwrap_or_default()
+    }
 
-        Self {
-            dir: DIR.get_dir(&path).unwrap_or(&DIR),
+    pub fn find(lang: &Lang, version: Version) -> Option<Self> {
+        if lang.structure_dir_name.is_empty() {
+            return None;
         }
+
+        let path = Path::new(version.dir_name()).join(&lang.structure_dir_name);
+
+        DIR.get_dir(&path).map(|dir| Self { dir })
     }
 
     pub fn entries(&self) -> Result<Vec<&DirEntry<'_>>, String> {
@@ -146,6 +201,11 @@ pub struct Structure {
     pub lang: Lang,
     pub included_dir: IncludedDir,
     pub command_post_generation: Vec<Vec<String>>,
+    pub is_confirmed: bool,
+    pub is_dry_run: bool,
+    pub is_keep_staging: bool,
+    pub is_minimal: bool,
+    pub is_offline: bool,
 }
 
 impl Structure {
@@ -157,6 +217,11 @@ impl Structure {
             lang,
             included_dir,
             command_post_generation: Vec::new(),
+            is_confirmed: false,
+            is_dry_run: false,
+            is_keep_staging: false,
+            is_minimal: false,
+            is_offline: false,
         }
     }
 
@@ -171,18 +236,62 @@ impl Structure {
             return Err("The language structure directory name is empty".to_string());
         }
 
-        Ok(())
+        self.lang.validate_preset()
     }
 
     pub fn get_entries(&self) -> Result<Vec<DirEntry<'_>>, String> {
         let entries = self.included_dir.entries()?;
-
-        Ok(entries
+        let entries: Vec<&DirEntry> = entries
             .into_iter()
             .filter(|entry| !path_contains_template(entry.path()))
+            .filter(|entry| self.is_selected_variant(entry.path()))
+            .filter(|entry| self.is_selected_example(entry.path()))
+            .collect();
+
+        Ok(prefer_variant_entries(entries)
+            .into_iter()
             .cloned()
             .collect())
     }
+
+    fn is_selected_example(&self, path: &Path) -> bool {
+        let relative = strip_variant_path(
+            path.strip_prefix(self.included_dir.dir.path())
+                .unwrap_or(path),
+        );
+        let is_minimal_overlay = path
+            .iter()
+            .any(|name| name.to_str() == Some(MINIMAL_OVERLAY_DIR_NAME));
+
+        if !self.is_minimal {
+            return !is_minimal_overlay;
+        }
+
+        !relative
+            .iter()
+            .filter_map(|name| name.to_str())
+            .any(|name| {
+                name == MINIMAL_OVERLAY_DIR_NAME
+                    || name.split('.').next().is_some_and(|stem| {
+                        EXAMPLE_FEATURE_NAMES
+                            .iter()
+                            .any(|example| stem.eq_ignore_ascii_case(example))
+                    })
+            })
+    }
+
+    fn is_selected_variant(&self, path: &Path) -> bool {
+        let relative = path
+            .strip_prefix(self.included_dir.dir.path())
+            .unwrap_or(path);
+
+        is_selected_variant_path(relative, |dir_name| match dir_name {
+            HTTP_VARIANT_DIR_NAME => Some(self.lang.http_framework().name()),
+            DB_VARIANT_DIR_NAME => Some(self.lang.database().name()),
+            TOOLCHAIN_VARIANT_DIR_NAME => Some(self.lang.python_toolchain().name()),
+            _ => None,
+        })
+    }
 }
 
 #[derive(Debug, Default, Clone)]
@@ -191,6 +300,7 @@ pub struct Template {
     pub part: Part,
     pub lang: Lang,
     pub included_dir: IncludedDir,
+    pub is_dry_run: bool,
 }
 
 impl Template {
@@ -202,6 +312,7 @@ impl Template {
             part,
             lang,
             included_dir,
+            is_dry_run: false,
         }
     }
 
@@ -222,16 +333,27 @@ impl Template {
         Ok(())
     }
 
+    pub fn validate_preset(&self) -> Result<(), String> {
+        if let Some(name) = &self.lang.preset.database {
+            if !matches!(self.part, Part::Feature) {
+                return Err("A database can only be chosen when adding a feature".to_string());
+            }
+            self.lang.validate_database(name)?;
+        }
+
+        Ok(())
+    }
+
     pub fn get_entries(&self) -> Result<Vec<DirEntry<'_>>, String> {
         let part_dir_name = self.part.dir_name();
         let entries = self.included_dir.entries()?;
 
-        Ok(entries
+        let entries: Vec<&DirEntry> = entries
             .into_iter()
             .filter(|entry| {
                 let path = entry.path();
                 let path_str = path.to_string_lossy();
-                if !path_str.contains(TEMPLATE_PREFIX_FILENAME) {
+                if !path_contains_template(path) {
                     return false;
                 }
 
@@ -240,21 +362,46 @@ impl Template {
                     _ => true,
                 }
             })
+            .filter(|entry| self.is_selected_variant(entry.path()))
+            .collect();
+
+        Ok(prefer_variant_entries(entries)
+            .into_iter()
             .cloned()
             .collect())
     }
+
+    fn is_selected_variant(&self, path: &Path) -> bool {
+        let relative = path
+            .strip_prefix(self.included_dir.dir.path())
+            .unwrap_or(path);
+
+        is_selected_variant_path(relative, |dir_name| match dir_name {
+            HTTP_VARIANT_DIR_NAME => Some(self.lang.http_framework().name()),
+            DB_VARIANT_DIR_NAME if self.lang.prop.database.is_valid() => {
+                Some(self.lang.prop.database.name())
+            }
+            TOOLCHAIN_VARIANT_DIR_NAME => Some(self.lang.python_toolchain().name()),
+            _ => None,
+        })
+    }
 }
 
 #[derive(Debug, Default, Clone, Copy)]
 pub struct Partial {
     pub version: Version,
     pub part: Part,
+    pub is_dry_run: bool,
 }
 
 impl Partial {
     #[inline]
     pub const fn new(version: Version, part: Part) -> Self {
-        Self { version, part }
+        Self {
+            version,
+            part,
+            is_dry_run: false,
+        }
     }
 
     #[inline]
@@ -350,12 +497,7 @@ impl Partial {
             if path.is_file() {
                 all_paths.push(path);
             } else if path.is_dir() {
-                let queue_ref = match dep_queue.as_mut() {
-                    Some(q) => Some(&mut **q),
-                    None => None,
-                };
-
-                self.handle_dir_dependency(&path, all_paths, queue_ref);
+                self.handle_dir_dependency(&path, all_paths, dep_queue.as_deref_mut());
             }
         }
     }
@@ -377,10 +519,10 @@ impl Partial {
                 all_paths.push(entry_path.clone());
 
                 if let Some(ref mut queue) = dep_queue {
-                    if file_name.eq_ignore_ascii_case(DEPENDENCY_FILENAME) {
-                        if !all_paths.contains(&entry_path) {
-                            queue.push(entry_path);
-                        }
+                    if file_name.eq_ignore_ascii_case(DEPENDENCY_FILENAME)
+                        && !all_paths.contains(&entry_path)
+                    {
+                        queue.push(entry_path);
                     }
                 }
             }
@@ -388,9 +530,102 @@ impl Partial {
     }
 }
 
+pub fn strip_variant_components<'a>(components: &[Component<'a>]) -> Vec<Component<'a>> {
+    let mut stripped = Vec::with_capacity(components.len());
+    let mut index = 0;
+
+    while index < components.len() {
+        match components[index] {
+            Component::Normal(name)
+                if index + 2 < components.len()
+                    && VARIANT_DIR_NAMES.contains(&name.to_str().unwrap_or_default()) =>
+            {
+                index += 2;
+            }
+            Component::Normal(name)
+                if index + 1 < components.len() && name == MINIMAL_OVERLAY_DIR_NAME =>
+            {
+                index += 1;
+            }
+            component => {
+                stripped.push(component);
+                index += 1;
+            }
+        }
+    }
+
+    stripped
+}
+
+fn is_selected_variant_path<'a, F>(path: &Path, selected_name: F) -> bool
+where
+    F: Fn(&str) -> Option<&'a str>,
+{
+    let mut names = path.iter().filter_map(|name| name.to_str()).peekable();
+
+    while let Some(name) = names.next() {
+        if VARIANT_DIR_NAMES.contains(&name)
+            && (names.next() != selected_name(name) || names.peek().is_none())
+        {
+            return false;
+        }
+    }
+
+    true
+}
+
+fn prefer_variant_entries<'a>(entries: Vec<&'a DirEntry<'a>>) -> Vec<&'a DirEntry<'a>> {
+    let variant_files: Vec<(PathBuf, usize)> = entries
+        .iter()
+        .filter(|entry| entry.as_file().is_some())
+        .map(|entry| {
+            (
+                strip_variant_path(entry.path()),
+                variant_depth(entry.path()),
+            )
+        })
+        .filter(|(_, depth)| *depth > 0)
+        .collect();
+
+    let mut dir_paths: HashSet<PathBuf> = HashSet::new();
+
+    entries
+        .into_iter()
+        .filter(|entry| {
+            let path = strip_variant_path(entry.path());
+            if entry.as_dir().is_some() {
+                return dir_paths.insert(path);
+            }
+
+            let depth = variant_depth(entry.path());
+
+            !variant_files.iter().any(|(variant_path, variant_depth)| {
+                *variant_path == path && *variant_depth > depth
+            })
+        })
+        .collect()
+}
+
 #[inline]
-fn path_contains_template(path: &Path) -> bool {
-    path.to_string_lossy().contains(TEMPLATE_PREFIX_FILENAME)
+fn variant_depth(path: &Path) -> usize {
+    path.iter()
+        .filter_map(|name| name.to_str())
+        .filter(|name| VARIANT_DIR_NAMES.contains(name) || *name == MINIMAL_OVERLAY_DIR_NAME)
+        .count()
+}
+
+#[inline]
+fn strip_variant_path(path: &Path) -> PathBuf {
+    let components: Vec<Component> = path.components().collect();
+
+    strip_variant_components(&components).iter().collect()
+}
+
+#[inline]
+pub fn path_contains_template(path: &Path) -> bool {
+    let path = path.to_string_lossy();
+
+    path.contains(TEMPLATE_PREFIX_FILENAME) || path.contains(TEMPLATE_PASCAL_CASE_PREFIX_FILENAME)
 }
 
 fn collect_dir_entries<'a>(dir: &'a Dir<'a>, entries: &mut Vec<&'a DirEntry<'a>>) {
@@ -7,6 +7,8 @@ use std::fs::{
 use std::path::{Path, PathBuf};
 use zip::ZipArchive;
 
+use crate::error::Error;
+
 pub static VERSION: &str = env!("CARGO_PKG_VERSION");
 
 #[inline]
@@ -27,16 +29,17 @@ pub fn file_stem<P: AsRef<Path>>(path: P) -> String {
         .to_string()
 }
 
-pub fn write_file<P: AsRef<Path>>(filepath: P, content: &str) -> Result<(), String> {
+pub fn write_file<P: AsRef<Path>>(filepath: P, content: &str) -> Result<(), Error> {
     let path = filepath.as_ref();
 
-    write(path, content).map_err(|err| format!("Failed to write {}: {}", path.display(), err))
+    write(path, content)
+        .map_err(|err| Error::io(format!("Failed to write {}", path.display()), err))
 }
 
-pub fn create_file<P: AsRef<Path>>(filepath: P, content: &str) -> Result<(), String> {
+pub fn create_file<P: AsRef<Path>>(filepath: P, content: &str) -> Result<(), Error> {
     let filepath = filepath.as_ref();
     if filepath.is_file() {
-        return Err(format!("File already exists: {}", filepath.display()));
+        return Err(Error::AlreadyExists(filepath.to_path_buf()));
     }
 
     let parent = parent_dir(filepath);
@@ -47,13 +50,14 @@ pub fn create_file<P: AsRef<Path>>(filepath: P, content: &str) -> Result<(), Str
     write_file(filepath, content)
 }
 
-pub fn remove_file<P: AsRef<Path>>(filepath: P) -> Result<(), String> {
+pub fn remove_file<P: AsRef<Path>>(filepath: P) -> Result<(), Error> {
     let filepath = filepath.as_ref();
     if !filepath.is_file() {
-        return Err(format!("File does not exist: {}", filepath.display()));
+        return Err(Error::NotFound(filepath.to_path_buf()));
     }
 
-    rm_file(filepath).map_err(|err| format!("Failed to remove {}: {}", filepath.display(), err))
+    rm_file(filepath)
+        .map_err(|err| Error::io(format!("Failed to remove {}", filepath.display()), err))
 }
 
 pub fn remove_paths<P: AsRef<Path>>(paths: Vec<P>) {
@@ -67,28 +71,26 @@ pub fn remove_paths<P: AsRef<Path>>(paths: Vec<P>) {
     }
 }
 
-pub fn read_file<P: AsRef<Path>>(path: P) -> Result<String, String> {
+pub fn read_file<P: AsRef<Path>>(path: P) -> Result<String, Error> {
     let path = path.as_ref();
     if !path.is_file() {
-        return Err(format!("File does not exist: {}", path.display()));
+        return Err(Error::NotFound(path.to_path_buf()));
     }
 
-    read_to_string(path).map_err(|err| format!("Failed to read {}: {}", path.display(), err))
+    read_to_string(path).map_err(|err| Error::io(format!("Failed to read {}", path.display()), err))
 }
 
 pub fn copy_file<P: AsRef<Path>, Q: AsRef<Path>>(
     source_path: P,
     destination_path: Q,
-) -> Result<(), String> {
+) -> Result<(), Error> {
     let src = source_path.as_ref();
     let dst = destination_path.as_ref();
 
     copy(src, dst).map(|_| ()).map_err(|err| {
-        format!(
-            "Failed to copy {} to {}: {}",
-            src.display(),
-            dst.display(),
-            err
+        Error::io(
+            format!("Failed to copy {} to {}", src.display(), dst.display()),
+            err,
         )
     })
 }
@@ -121,30 +123,27 @@ pub fn ls<P: AsRef<Path>>(dir: P) -> Vec<PathBuf> {
         .collect()
 }
 
-pub fn create_dir<P: AsRef<Path>>(dir: P) -> Result<(), String> {
+pub fn create_dir<P: AsRef<Path>>(dir: P) -> Result<(), Error> {
     let dir = dir.as_ref();
     if dir.is_dir() {
         return Ok(());
     }
     if dir.exists() {
-        return Err(format!(
-            "Path exists but is not a directory: {}",
-            dir.display()
-        ));
+        return Err(Error::NotADirectory(dir.to_path_buf()));
     }
 
     create_dir_all(dir)
-        .map_err(|err| format!("Failed to create directory {}: {}", dir.display(), err))
+        .map_err(|err| Error::io(format!("Failed to create directory {}", dir.display()), err))
 }
 
-pub fn remove_dir<P: AsRef<Path>>(dir: P) -> Result<(), String> {
+pub fn remove_dir<P: AsRef<Path>>(dir: P) -> Result<(), Error> {
     let dir = dir.as_ref();
     if !dir.is_dir() {
-        return Err(format!("Directory does not exist: {}", dir.display()));
+        return Err(Error::NotFound(dir.to_path_buf()));
     }
 
     remove_dir_all(dir)
-        .map_err(|err| format!("Failed to remove directory {}: {}", dir.display(), err))
+        .map_err(|err| Error::io(format!("Failed to remove directory {}", dir.display()), err))
 }
 
 #[inline]
@@ -191,14 +190,14 @@ pub fn is_dir_has<P: AsRef<Path>>(dir: P, files: &[&str], extensions: &[&str]) -
     false
 }
 
-pub fn remove_file_or_dir<P: AsRef<Path>>(path: P) -> Result<(), String> {
+pub fn remove_file_or_dir<P: AsRef<Path>>(path: P) -> Result<(), Error> {
     let path = path.as_ref();
     if path.is_dir() {
         remove_dir(path)
     } else if path.is_file() {
         remove_file(path)
     } else {
-        Err("Invalid path".to_string())
+        Err(Error::NotFound(path.to_path_buf()))
     }
 }
 
@@ -210,11 +209,18 @@ pub fn app_exe_path() -> PathBuf {
 pub fn extract_archive<P: AsRef<Path>, Q: AsRef<Path>>(
     path: P,
     output_dir: Q,
-) -> Result<(), String> {
-    let file = File::open(path).map_err(|e| e.to_string())?;
-    let mut zip = ZipArchive::new(file).map_err(|e| e.to_string())?;
+) -> Result<(), Error> {
+    let path = path.as_ref();
+    let archive_error = |source| Error::Archive {
+        path: path.to_path_buf(),
+        source,
+    };
+
+    let file = File::open(path)
+        .map_err(|err| Error::io(format!("Failed to open {}", path.display()), err))?;
+    let mut zip = ZipArchive::new(file).map_err(archive_error)?;
 
-    zip.extract(output_dir).map_err(|e| e.to_string())
+    zip.extract(output_dir).map_err(archive_error)
 }
 
 #[inline]